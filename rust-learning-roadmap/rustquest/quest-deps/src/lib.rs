@@ -0,0 +1,2 @@
+// Package kosong: hanya dipakai oleh `cargo vendor` untuk mengumpulkan
+// dependency exercise Cargo RustQuest.
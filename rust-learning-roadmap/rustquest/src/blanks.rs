@@ -0,0 +1,90 @@
+// Fill-in-the-Blank - Answer Only the `____` Parts
+// ================================================
+
+/// Penanda bagian kosong di `code_template`.
+pub const BLANK: &str = "____";
+
+/// Satu bagian kosong di template.
+pub struct Blank {
+    /// Posisi byte `____` di template.
+    pub offset: usize,
+    /// Nomor baris (mulai dari 1) tempat blank berada.
+    pub line: usize,
+}
+
+pub fn find_blanks(template: &str) -> Vec<Blank> {
+    template
+        .match_indices(BLANK)
+        .map(|(offset, _)| Blank {
+            offset,
+            line: template[..offset].matches('\n').count() + 1,
+        })
+        .collect()
+}
+
+/// Tampilkan baris tempat blank ke-`index` berada, dengan blank tersebut
+/// ditandai `[#n]` agar learner tahu bagian mana yang sedang diisi.
+pub fn context_line(template: &str, blanks: &[Blank], index: usize) -> String {
+    let blank = &blanks[index];
+    let line_start = template[..blank.offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = template[blank.offset..]
+        .find('\n')
+        .map_or(template.len(), |i| blank.offset + i);
+
+    let marker = format!("[#{}]", index + 1);
+    format!(
+        "{}{}{}",
+        &template[line_start..blank.offset],
+        marker,
+        &template[blank.offset + BLANK.len()..line_end]
+    )
+    .trim()
+    .to_string()
+}
+
+/// Ganti setiap blank dengan jawaban learner secara berurutan.
+pub fn fill(template: &str, blanks: &[Blank], answers: &[String]) -> String {
+    let mut code = String::with_capacity(template.len());
+    let mut last = 0;
+    for (blank, answer) in blanks.iter().zip(answers) {
+        code.push_str(&template[last..blank.offset]);
+        code.push_str(answer);
+        last = blank.offset + BLANK.len();
+    }
+    code.push_str(&template[last..]);
+    code
+}
+
+/// Blank yang paling dekat dengan `line` (misal baris panic): blank di
+/// baris itu, blank terakhir sebelumnya, atau blank pertama sesudahnya.
+pub fn nearest(blanks: &[Blank], line: usize) -> Vec<usize> {
+    let found = suspects(blanks, &[line]);
+    if !found.is_empty() {
+        return found;
+    }
+    blanks.iter().position(|blank| blank.line > line).into_iter().collect()
+}
+
+/// Tebak blank mana yang kemungkinan salah berdasarkan baris yang ditunjuk
+/// compiler. Jika error tidak berada di baris blank, ambil blank terdekat
+/// sebelum baris error.
+pub fn suspects(blanks: &[Blank], error_lines: &[usize]) -> Vec<usize> {
+    let mut suspects = Vec::new();
+    for &line in error_lines {
+        let on_line: Vec<usize> = (0..blanks.len())
+            .filter(|&i| blanks[i].line == line)
+            .collect();
+        let found = if on_line.is_empty() {
+            (0..blanks.len()).rev().find(|&i| blanks[i].line < line).into_iter().collect()
+        } else {
+            on_line
+        };
+        for i in found {
+            if !suspects.contains(&i) {
+                suspects.push(i);
+            }
+        }
+    }
+    suspects.sort_unstable();
+    suspects
+}
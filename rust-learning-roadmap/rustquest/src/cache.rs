@@ -0,0 +1,242 @@
+// Compile Cache - Reuse Builds of Identical Submissions
+// =====================================================
+
+use crate::diagnostics::Diagnostic;
+use crate::toolchain;
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Env untuk memindahkan folder cache.
+const CACHE_ENV: &str = "RUSTQUEST_CACHE";
+
+/// Batas total ukuran cache sebelum entry paling lama tidak dipakai dihapus.
+const MAX_CACHE_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Target dir Cargo bersama untuk exercise Cargo, di dalam folder cache.
+const CARGO_TARGET_DIR: &str = "cargo-target";
+
+/// Kunci target dir Cargo. Sengaja di luar target dir agar tetap ada saat
+/// target dir dihapus; waktu modifikasinya menandai build terakhir.
+const CARGO_LOCK_FILE: &str = "cargo-target.lock";
+
+/// Penanda folder staging `{key}.tmp-{pid}` yang belum di-rename.
+const STAGING_MARKER: &str = ".tmp-";
+
+const DIAGNOSTICS_FILE: &str = "diagnostics.json";
+const LAST_USED_FILE: &str = "last_used";
+
+/// Hasil build yang tersimpan di cache.
+pub enum Entry {
+    /// Build berhasil; berisi executable yang tersimpan di cache.
+    Built(Vec<PathBuf>),
+    /// Build gagal dengan diagnostic compiler ini.
+    Failed(Vec<Diagnostic>),
+}
+
+/// Buat key cache dari semua input build: source, versi toolchain, dan
+/// flag compile. Setiap bagian diberi prefix panjang agar gabungan yang
+/// berbeda tidak menghasilkan key yang sama.
+pub fn key(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    // Versi toolchain ikut di key agar update rustc tidak memakai binary lama
+    let toolchain = toolchain::current().map(|t| t.verbose.as_str()).unwrap_or_default();
+    hasher.update(toolchain.as_bytes());
+    for part in parts {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Target dir Cargo milik user ini, dipakai bersama oleh semua build
+/// exercise Cargo agar dependency cukup di-compile sekali.
+pub fn cargo_target_dir() -> PathBuf {
+    cache_dir().join(CARGO_TARGET_DIR)
+}
+
+/// Kunci eksklusif target dir Cargo, lepas saat file yang dikembalikan
+/// ditutup. Dipegang selama build dan saat target dir dihapus oleh
+/// eviction. Tanpa `wait`, gagal dengan `WouldBlock` jika sedang dipakai.
+pub fn lock_cargo_target(wait: bool) -> io::Result<fs::File> {
+    let root = cache_dir();
+    fs::create_dir_all(&root)?;
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(root.join(CARGO_LOCK_FILE))?;
+    let operation = if wait { libc::LOCK_EX } else { libc::LOCK_EX | libc::LOCK_NB };
+    // SAFETY: flock pada file descriptor yang valid selama `file` hidup.
+    if unsafe { libc::flock(file.as_raw_fd(), operation) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(file)
+}
+
+pub fn lookup(key: &str) -> Option<Entry> {
+    let dir = cache_dir().join(key);
+    if !dir.is_dir() {
+        return None;
+    }
+    touch(&dir);
+
+    let diagnostics = dir.join(DIAGNOSTICS_FILE);
+    if diagnostics.exists() {
+        let data = fs::read_to_string(diagnostics).ok()?;
+        return serde_json::from_str(&data).ok().map(Entry::Failed);
+    }
+
+    let mut executables: Vec<PathBuf> = fs::read_dir(&dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.file_name().is_some_and(|n| n.to_string_lossy().starts_with("bin-")))
+        .collect();
+    executables.sort();
+    if executables.is_empty() {
+        None
+    } else {
+        Some(Entry::Built(executables))
+    }
+}
+
+/// Simpan executable hasil build. Kegagalan menulis cache diabaikan karena
+/// cache hanya optimasi.
+pub fn store_built(key: &str, executables: &[PathBuf]) {
+    let _ = store(key, |dir| {
+        for (i, executable) in executables.iter().enumerate() {
+            fs::copy(executable, dir.join(format!("bin-{:02}", i)))?;
+        }
+        Ok(())
+    });
+}
+
+pub fn store_failed(key: &str, diagnostics: &[Diagnostic]) {
+    let _ = store(key, |dir| {
+        let data = serde_json::to_string(diagnostics).map_err(io::Error::other)?;
+        fs::write(dir.join(DIAGNOSTICS_FILE), data)
+    });
+}
+
+fn store(key: &str, write: impl FnOnce(&Path) -> io::Result<()>) -> io::Result<()> {
+    let root = cache_dir();
+    let dir = root.join(key);
+    // Tulis ke folder sementara lalu rename agar entry tidak pernah
+    // setengah jadi saat dibaca session lain.
+    let staging = root.join(format!("{}{}{}", key, STAGING_MARKER, std::process::id()));
+    fs::create_dir_all(&staging)?;
+    if let Err(e) = write(&staging) {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+    touch(&staging);
+    if fs::rename(&staging, &dir).is_err() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    evict(&root);
+    Ok(())
+}
+
+/// Hapus entry yang paling lama tidak dipakai sampai total ukuran cache di
+/// bawah `MAX_CACHE_BYTES`. Target dir Cargo dihitung sebagai satu entry
+/// dan hanya dihapus jika tidak sedang dipakai build lain. Folder staging
+/// milik session lain yang sedang menulis tidak ikut dihapus.
+fn evict(root: &Path) {
+    let Ok(entries) = fs::read_dir(root) else {
+        return;
+    };
+    let mut entries: Vec<(PathBuf, SystemTime, u64)> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_dir() && !path.ends_with(CARGO_TARGET_DIR) && !is_staging(path))
+        .map(|path| {
+            let used = fs::metadata(path.join(LAST_USED_FILE))
+                .and_then(|meta| meta.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            let size = dir_size(&path);
+            (path, used, size)
+        })
+        .collect();
+    let target = root.join(CARGO_TARGET_DIR);
+    if target.is_dir() {
+        let used = fs::metadata(root.join(CARGO_LOCK_FILE))
+            .and_then(|meta| meta.modified())
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let size = tree_size(&target);
+        entries.push((target.clone(), used, size));
+    }
+
+    let mut total: u64 = entries.iter().map(|(_, _, size)| size).sum();
+    entries.sort_by_key(|(_, used, _)| *used);
+    for (path, _, size) in entries {
+        if total <= MAX_CACHE_BYTES {
+            break;
+        }
+        let _lock = if path == target {
+            match lock_cargo_target(false) {
+                Ok(lock) => Some(lock),
+                Err(_) => continue,
+            }
+        } else {
+            None
+        };
+        if fs::remove_dir_all(&path).is_ok() {
+            total -= size;
+        }
+    }
+}
+
+fn is_staging(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().contains(STAGING_MARKER))
+}
+
+fn dir_size(dir: &Path) -> u64 {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.metadata().ok())
+                .map(|meta| meta.len())
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Ukuran semua file di bawah `dir`, termasuk subfolder. Symlink tidak
+/// diikuti.
+fn tree_size(dir: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let meta = fs::symlink_metadata(entry.path()).ok()?;
+            Some(if meta.is_dir() { tree_size(&entry.path()) } else { meta.len() })
+        })
+        .sum()
+}
+
+fn touch(dir: &Path) {
+    let _ = fs::write(dir.join(LAST_USED_FILE), "");
+}
+
+fn cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(CACHE_ENV) {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return PathBuf::from(dir).join("rustquest");
+    }
+    if let Some(home) = env::var_os("HOME") {
+        return PathBuf::from(home).join(".cache").join("rustquest");
+    }
+    env::temp_dir().join("rustquest-cache")
+}
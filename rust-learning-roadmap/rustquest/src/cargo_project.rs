@@ -0,0 +1,148 @@
+// Cargo Project Exercises - Offline Builds with Vendored Crates
+// =============================================================
+
+use crate::cache;
+use crate::diagnostics::Diagnostic;
+use crate::packs;
+use serde::Deserialize;
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+/// File yang diisi learner di dalam project.
+pub const MAIN_FILE: &str = "src/main.rs";
+
+/// Env untuk menunjuk folder hasil `cargo vendor` secara manual.
+const VENDOR_ENV: &str = "RUSTQUEST_VENDOR";
+
+/// Template project Cargo untuk sebuah exercise.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CargoProject {
+    /// File project selain `src/main.rs` (path relatif -> isi), termasuk
+    /// `Cargo.toml` dan file test di `tests/`.
+    pub files: Vec<(String, String)>,
+}
+
+pub struct CargoBuild {
+    pub success: bool,
+    /// Executable hasil build (binary utama, atau harness test jika
+    /// build dilakukan dengan `tests = true`), disalin ke folder project.
+    pub executables: Vec<PathBuf>,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Pesan cargo yang bukan diagnostic compiler, misal dependency yang
+    /// tidak ditemukan secara offline.
+    pub stderr: String,
+}
+
+/// Tulis project ke `dir`: semua file template, `src/main.rs` berisi kode
+/// learner, dan konfigurasi Cargo untuk memakai crate vendor.
+pub fn prepare(project: &CargoProject, dir: &Path, main_code: &str) -> io::Result<()> {
+    for (path, content) in &project.files {
+        // Sudah dicek saat load, dicek lagi karena file ditulis ke disk
+        if !packs::is_inside(path) {
+            return Err(io::Error::other(format!("path `{}` keluar dari folder project", path)));
+        }
+        write_file(&dir.join(path), content)?;
+    }
+    write_file(&dir.join(MAIN_FILE), main_code)?;
+
+    if let Some(vendor) = vendor_dir() {
+        let config = format!(
+            "[source.crates-io]\nreplace-with = \"rustquest-vendor\"\n\n[source.rustquest-vendor]\ndirectory = {:?}\n",
+            vendor.display().to_string()
+        );
+        write_file(&dir.join(".cargo/config.toml"), &config)?;
+    }
+    Ok(())
+}
+
+/// Apakah crate vendor tersedia. Tanpa vendor, exercise Cargo tidak bisa
+/// di-build offline dan ditandai tidak tersedia.
+pub fn vendor_available() -> bool {
+    vendor_dir().is_some()
+}
+
+/// Build project di `dir` secara offline. Dengan `tests = true`, build
+/// semua harness test tanpa menjalankannya.
+pub fn build(dir: &Path, tests: bool) -> io::Result<CargoBuild> {
+    // Semua project bernama sama, jadi target dir dikunci sampai binary
+    // disalin agar tidak tertimpa build dari session lain atau dihapus
+    // eviction cache.
+    let lock = cache::lock_cargo_target(true)?;
+    // Tandai waktu pakai terakhir untuk eviction cache
+    let _ = lock.set_modified(SystemTime::now());
+    let target_dir = cache::cargo_target_dir();
+    fs::create_dir_all(&target_dir)?;
+
+    let mut command = Command::new("cargo");
+    if tests {
+        command.args(["test", "--no-run"]);
+    } else {
+        command.arg("build");
+    }
+    let output = command
+        .args(["--offline", "--quiet", "--message-format=json"])
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .current_dir(dir)
+        .output()?;
+
+    let mut executables = Vec::new();
+    let mut diagnostics = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Ok(message) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        match message["reason"].as_str() {
+            Some("compiler-artifact") => {
+                if let Some(path) = message["executable"].as_str() {
+                    let copy = dir.join(format!("bin-{:02}", executables.len()));
+                    fs::copy(path, &copy)?;
+                    executables.push(copy);
+                }
+            }
+            Some("compiler-message") => {
+                if let Ok(diagnostic) = serde_json::from_value::<Diagnostic>(message["message"].clone()) {
+                    if diagnostic.is_error() && !diagnostic.message.starts_with("aborting due to") {
+                        diagnostics.push(diagnostic);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(CargoBuild {
+        success: output.status.success(),
+        executables,
+        diagnostics,
+        stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    })
+}
+
+/// Folder crate vendor yang ikut didistribusikan bersama RustQuest.
+fn vendor_dir() -> Option<PathBuf> {
+    let candidates = [
+        env::var_os(VENDOR_ENV).map(PathBuf::from),
+        env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(|dir| dir.join("vendor"))),
+        Some(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("vendor")),
+    ];
+    candidates
+        .into_iter()
+        .flatten()
+        .find(|dir| dir.is_dir())
+        .and_then(|dir| dir.canonicalize().ok())
+}
+
+fn write_file(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)
+}
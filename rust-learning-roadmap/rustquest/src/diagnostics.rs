@@ -0,0 +1,239 @@
+// Compiler Diagnostics - Beginner-Friendly rustc Errors
+// =====================================================
+
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+/// Satu diagnostic dari `rustc --error-format=json`. Hanya field yang
+/// dipakai RustQuest yang dibaca.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<DiagnosticCode>,
+    pub level: String,
+    pub spans: Vec<Span>,
+    #[serde(default)]
+    pub children: Vec<Diagnostic>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiagnosticCode {
+    pub code: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Span {
+    pub file_name: String,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    pub label: Option<String>,
+}
+
+/// Penjelasan singkat berbahasa Indonesia untuk error code yang sering
+/// ditemui pemula, beserta folder materi yang membahasnya.
+struct Explanation {
+    code: &'static str,
+    title: &'static str,
+    text: &'static str,
+    lesson: &'static str,
+}
+
+const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        code: "E0382",
+        title: "use after move",
+        text: "Nilai sudah di-move ke variable/fungsi lain, jadi variable lama tidak valid lagi. \
+               Pinjam dengan reference (&) atau buat salinan dengan .clone().",
+        lesson: "01_fundamentals/04_ownership",
+    },
+    Explanation {
+        code: "E0499",
+        title: "dua mutable borrow sekaligus",
+        text: "Hanya boleh ada SATU mutable reference (&mut) ke sebuah nilai dalam satu waktu. \
+               Selesaikan pemakaian reference pertama sebelum membuat yang kedua.",
+        lesson: "01_fundamentals/04_ownership",
+    },
+    Explanation {
+        code: "E0502",
+        title: "borrow mutable dan immutable bersamaan",
+        text: "Selama masih ada reference biasa (&) yang dipakai, nilai tidak boleh dipinjam \
+               sebagai &mut. Atur urutan kode agar keduanya tidak tumpang tindih.",
+        lesson: "01_fundamentals/04_ownership",
+    },
+    Explanation {
+        code: "E0596",
+        title: "meminjam variable immutable sebagai mutable",
+        text: "Untuk membuat &mut, variable aslinya harus dideklarasikan dengan `let mut`.",
+        lesson: "01_fundamentals/04_ownership",
+    },
+    Explanation {
+        code: "E0308",
+        title: "tipe tidak cocok",
+        text: "Tipe nilai berbeda dengan tipe yang diharapkan. Cek tipe variable, parameter, \
+               dan nilai return; ingat \"teks\" adalah &str, bukan String.",
+        lesson: "01_fundamentals/01_variables",
+    },
+    Explanation {
+        code: "E0384",
+        title: "assign dua kali ke variable immutable",
+        text: "Variable di Rust immutable by default. Tambahkan `mut` jika nilainya perlu \
+               diubah: let mut x = 0;",
+        lesson: "01_fundamentals/01_variables",
+    },
+    Explanation {
+        code: "E0425",
+        title: "nama tidak ditemukan",
+        text: "Variable atau fungsi ini belum dideklarasikan di scope ini. Cek ejaan dan \
+               pastikan dideklarasikan sebelum dipakai.",
+        lesson: "01_fundamentals/01_variables",
+    },
+    Explanation {
+        code: "E0061",
+        title: "jumlah argumen salah",
+        text: "Fungsi dipanggil dengan jumlah argumen yang berbeda dari deklarasinya.",
+        lesson: "01_fundamentals/03_functions",
+    },
+    Explanation {
+        code: "E0004",
+        title: "match belum mencakup semua kemungkinan",
+        text: "Match di Rust harus exhaustive. Tambahkan arm yang kurang atau gunakan `_`.",
+        lesson: "01_fundamentals/06_pattern_matching",
+    },
+    Explanation {
+        code: "E0599",
+        title: "method tidak ditemukan",
+        text: "Tipe ini tidak punya method tersebut. Cek ejaan nama method dan tipe nilainya.",
+        lesson: "01_fundamentals/05_structs_enums",
+    },
+    Explanation {
+        code: "E0106",
+        title: "lifetime belum ditulis",
+        text: "Fungsi yang mengembalikan reference perlu anotasi lifetime, misal \
+               fn f<'a>(x: &'a str) -> &'a str.",
+        lesson: "02_intermediate/04_lifetimes",
+    },
+    Explanation {
+        code: "E0597",
+        title: "nilai tidak hidup cukup lama",
+        text: "Reference masih dipakai setelah nilai yang dipinjam sudah di-drop. \
+               Pindahkan nilai ke scope yang lebih luas.",
+        lesson: "02_intermediate/04_lifetimes",
+    },
+    Explanation {
+        code: "E0277",
+        title: "trait tidak diimplementasikan",
+        text: "Tipe ini tidak mengimplementasikan trait yang dibutuhkan (misal Display untuk \
+               println!(\"{}\")). Gunakan {:?} atau implementasikan trait-nya.",
+        lesson: "02_intermediate/03_traits",
+    },
+];
+
+impl Diagnostic {
+    pub fn is_error(&self) -> bool {
+        self.level == "error" || self.level == "error: internal compiler error"
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_ref().map(|c| c.code.as_str())
+    }
+
+    /// Ringkasan satu baris, misal `error[E0382]: borrow of moved value (baris 5)`.
+    pub fn summary(&self, file: &str) -> String {
+        let mut summary = match self.code() {
+            Some(code) => format!("error[{}]: {}", code, self.message),
+            None => format!("error: {}", self.message),
+        };
+        if let Some(line) = self.primary_line(file) {
+            summary.push_str(&format!(" (baris {})", line));
+        }
+        summary
+    }
+
+    /// Baris utama yang ditunjuk diagnostic di file learner.
+    pub fn primary_line(&self, file: &str) -> Option<usize> {
+        self.spans
+            .iter()
+            .find(|span| span.is_primary && span.file_name == file)
+            .map(|span| span.line_start)
+    }
+}
+
+/// Baca diagnostic error dari stderr `rustc --error-format=json`. Baris yang
+/// bukan JSON, warning, dan ringkasan "aborting due to ..." dilewati.
+pub fn parse(stderr: &str) -> Vec<Diagnostic> {
+    stderr
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Diagnostic>(line).ok())
+        .filter(|diagnostic| diagnostic.is_error() && !diagnostic.message.starts_with("aborting due to"))
+        .collect()
+}
+
+/// Tampilkan diagnostic dengan potongan kode learner, caret di bawah bagian
+/// yang bermasalah, dan penjelasan singkat jika error code-nya dikenal.
+/// `file` adalah nama file source learner seperti yang diberikan ke rustc,
+/// span di file lain (misal dari standard library) tidak ditampilkan.
+pub fn render(diagnostic: &Diagnostic, source: &str, file: &str) {
+    let header = match diagnostic.code() {
+        Some(code) => format!("error[{}]: {}", code, diagnostic.message),
+        None => format!("error: {}", diagnostic.message),
+    };
+    println!("{}", header.red().bold());
+
+    let lines: Vec<&str> = source.lines().collect();
+    let mut spans: Vec<&Span> = diagnostic
+        .spans
+        .iter()
+        .filter(|span| span.file_name == file)
+        .collect();
+    spans.sort_by_key(|span| (span.line_start, span.column_start));
+
+    if let Some(primary) = spans.iter().find(|span| span.is_primary) {
+        println!("  {} baris {}, kolom {}", "-->".blue(), primary.line_start, primary.column_start);
+    }
+
+    let width = spans.iter().map(|s| s.line_start).max().unwrap_or(0).to_string().len();
+    let gutter = " ".repeat(width);
+    if !spans.is_empty() {
+        println!("  {} {}", gutter, "|".blue());
+    }
+    for span in &spans {
+        let Some(text) = lines.get(span.line_start - 1) else {
+            continue;
+        };
+        println!("  {:>width$} {} {}", span.line_start, "|".blue(), text, width = width);
+
+        let end = if span.line_end == span.line_start {
+            span.column_end
+        } else {
+            text.chars().count() + 1
+        };
+        let length = end.saturating_sub(span.column_start).max(1);
+        let marker = if span.is_primary { "^" } else { "-" }.repeat(length);
+        let label = span.label.as_deref().unwrap_or("");
+        let pointer = format!("{}{} {}", " ".repeat(span.column_start - 1), marker, label);
+        let pointer = if span.is_primary { pointer.red().bold() } else { pointer.blue() };
+        println!("  {} {} {}", gutter, "|".blue(), pointer);
+    }
+
+    for child in &diagnostic.children {
+        if child.level == "help" || child.level == "note" {
+            println!("  {} {} {}", gutter, "=".blue(), format!("{}: {}", child.level, child.message).white());
+        }
+    }
+
+    if let Some(explanation) = diagnostic
+        .code()
+        .and_then(|code| EXPLANATIONS.iter().find(|e| e.code == code))
+    {
+        println!(
+            "{} {}",
+            format!("💡 {} ({}):", explanation.code, explanation.title).yellow().bold(),
+            explanation.text.yellow()
+        );
+        println!("{} {}", "📖 Pelajari lagi:".cyan(), explanation.lesson.cyan().bold());
+    }
+    println!();
+}
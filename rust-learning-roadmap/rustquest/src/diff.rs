@@ -0,0 +1,240 @@
+// Diff - Colored Line-by-Line Comparison
+// ======================================
+
+use colored::{ColoredString, Colorize};
+use similar::{Algorithm, ChangeTag, DiffOp, TextDiff};
+
+/// Baris konteks yang ditampilkan di sekitar setiap perubahan.
+const CONTEXT_LINES: usize = 3;
+
+/// Tampilkan perbedaan `old` dan `new` per baris: baris yang dihapus merah
+/// dengan `-`, baris yang ditambah hijau dengan `+`.
+pub fn print_diff(old_label: &str, new_label: &str, old: &str, new: &str) {
+    let diff = TextDiff::from_lines(old, new);
+    let groups = diff.grouped_ops(CONTEXT_LINES);
+    if groups.is_empty() {
+        println!("{}", "✅ Tidak ada perbedaan.".green());
+        return;
+    }
+
+    println!("{}", format!("--- {}", old_label).red().bold());
+    println!("{}", format!("+++ {}", new_label).green().bold());
+    for (i, group) in groups.iter().enumerate() {
+        if i > 0 {
+            println!("{}", "  ...".blue());
+        }
+        for op in group {
+            for change in diff.iter_changes(op) {
+                // Baris yang dihapus diberi nomor dari teks lama
+                let index = match change.tag() {
+                    ChangeTag::Delete => change.old_index(),
+                    _ => change.new_index(),
+                };
+                let line_number = index.map(|index| index + 1).unwrap_or(0);
+                let text = change.value().trim_end_matches('\n');
+                let line = match change.tag() {
+                    ChangeTag::Delete => format!("- {}", text).red(),
+                    ChangeTag::Insert => format!("+ {}", text).green(),
+                    ChangeTag::Equal => format!("  {}", text).white(),
+                };
+                println!("{} {}", format!("{:>4} |", line_number).blue(), line);
+            }
+        }
+    }
+}
+
+/// Tampilkan perbedaan output yang diharapkan dengan output learner,
+/// sejajar per baris. Karakter yang berbeda di baris yang berubah disorot,
+/// dan karakter tak terlihat (spasi di akhir baris, tab) ditampilkan.
+pub fn print_output_diff(expected: &str, actual: &str) {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let ops = similar::capture_diff_slices(Algorithm::Myers, &expected_lines, &actual_lines);
+
+    println!(
+        "\n{} ({}, {})",
+        "📊 Perbedaan output:".yellow().bold(),
+        "- expected".red(),
+        "+ output kamu".green()
+    );
+
+    let mut changed = Vec::new();
+    let mut missing = Vec::new();
+    let mut extra = Vec::new();
+    for (i, group) in similar::group_diff_ops(ops, CONTEXT_LINES).iter().enumerate() {
+        if i > 0 {
+            println!("{}", "  ...".blue());
+        }
+        for op in group {
+            let (_, old_range, new_range) = op.as_tag_tuple();
+            let old = &expected_lines[old_range.clone()];
+            let new = &actual_lines[new_range.clone()];
+            if let DiffOp::Equal { .. } = op {
+                for (offset, line) in new.iter().enumerate() {
+                    print_line(new_range.start + offset, " ", &plain(line), |s| s.white());
+                }
+                continue;
+            }
+
+            // Pasangkan baris yang mirip sebagai "berubah"; sisanya kurang/lebih
+            for (old_offset, new_offset) in align(old, new) {
+                match (old_offset, new_offset) {
+                    (Some(o), Some(n)) => {
+                        let (old_chars, new_chars) = char_changes(old[o], new[n]);
+                        print_line(old_range.start + o, "-", &old_chars, |s| s.red());
+                        print_line(new_range.start + n, "+", &new_chars, |s| s.green());
+                        changed.push(new_range.start + n + 1);
+                    }
+                    (Some(o), None) => {
+                        print_line(old_range.start + o, "-", &plain(old[o]), |s| s.red());
+                        missing.push(old_range.start + o + 1);
+                    }
+                    (None, Some(n)) => {
+                        print_line(new_range.start + n, "+", &plain(new[n]), |s| s.green());
+                        extra.push(new_range.start + n + 1);
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+    }
+
+    if !changed.is_empty() {
+        println!("  {} {}", "✏️  Baris berbeda:".yellow(), line_list(&changed));
+    }
+    if !missing.is_empty() {
+        println!("  {} {}", "➖ Baris yang kurang (baris expected):".yellow(), line_list(&missing));
+    }
+    if !extra.is_empty() {
+        println!("  {} {}", "➕ Baris berlebih:".yellow(), line_list(&extra));
+    }
+    if !actual.is_empty() && !actual.ends_with('\n') {
+        println!(
+            "  {}",
+            "↵  Output tidak diakhiri newline (pakai println! alih-alih print!?)".yellow()
+        );
+    }
+    println!("  {}", "(· = spasi di akhir baris, → = tab)".white().dimmed());
+}
+
+/// Kemiripan minimal agar dua baris dianggap versi berubah dari baris yang
+/// sama, bukan baris yang kurang dan baris yang berlebih.
+const SIMILAR_LINE_RATIO: f32 = 0.5;
+
+/// Susun baris yang berbeda menjadi pasangan (expected, output) berurutan
+/// dengan total kemiripan terbesar. `None` di salah satu sisi berarti baris
+/// kurang atau berlebih.
+fn align(old: &[&str], new: &[&str]) -> Vec<(Option<usize>, Option<usize>)> {
+    let ratio = |o: usize, n: usize| TextDiff::from_chars(old[o], new[n]).ratio();
+
+    // best[i][j] = skor terbaik untuk old[i..] dan new[j..]
+    let mut best = vec![vec![0.0f32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            let skip = best[i + 1][j].max(best[i][j + 1]);
+            let r = ratio(i, j);
+            best[i][j] = if r >= SIMILAR_LINE_RATIO { skip.max(best[i + 1][j + 1] + r) } else { skip };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        let r = ratio(i, j);
+        if r >= SIMILAR_LINE_RATIO && best[i][j] == best[i + 1][j + 1] + r {
+            pairs.push((Some(i), Some(j)));
+            i += 1;
+            j += 1;
+        } else if best[i][j] == best[i + 1][j] {
+            pairs.push((Some(i), None));
+            i += 1;
+        } else {
+            pairs.push((None, Some(j)));
+            j += 1;
+        }
+    }
+    pairs.extend((i..old.len()).map(|i| (Some(i), None)));
+    pairs.extend((j..new.len()).map(|j| (None, Some(j))));
+    pairs
+}
+
+/// Karakter satu baris beserta tanda apakah karakter itu berbeda.
+type Marked = Vec<(char, bool)>;
+
+fn plain(line: &str) -> Marked {
+    line.chars().map(|c| (c, false)).collect()
+}
+
+/// Bandingkan dua baris per karakter dan tandai karakter yang berbeda di
+/// masing-masing sisi.
+fn char_changes(old: &str, new: &str) -> (Marked, Marked) {
+    let diff = TextDiff::from_chars(old, new);
+    let mut old_chars = Vec::new();
+    let mut new_chars = Vec::new();
+    for change in diff.iter_all_changes() {
+        for c in change.value().chars() {
+            match change.tag() {
+                ChangeTag::Equal => {
+                    old_chars.push((c, false));
+                    new_chars.push((c, false));
+                }
+                ChangeTag::Delete => old_chars.push((c, true)),
+                ChangeTag::Insert => new_chars.push((c, true)),
+            }
+        }
+    }
+    (old_chars, new_chars)
+}
+
+fn print_line(index: usize, sign: &str, chars: &Marked, color: impl Fn(&str) -> ColoredString) {
+    // Spasi setelah karakter non-whitespace terakhir adalah trailing whitespace
+    let trailing = chars
+        .iter()
+        .rposition(|(c, _)| !c.is_whitespace())
+        .map(|i| i + 1)
+        .unwrap_or(0);
+
+    // Gabungkan karakter dengan gaya yang sama agar tidak setiap karakter
+    // diberi kode warna sendiri
+    let mut runs: Vec<(String, Style)> = Vec::new();
+    for (i, &(c, marked)) in chars.iter().enumerate() {
+        let (text, invisible) = match c {
+            '\t' => ('→', true),
+            '\r' => ('␍', true),
+            ' ' if i >= trailing => ('·', true),
+            _ => (c, false),
+        };
+        let style = if marked {
+            Style::Changed
+        } else if invisible {
+            Style::Invisible
+        } else {
+            Style::Normal
+        };
+        match runs.last_mut() {
+            Some((run, last)) if *last == style => run.push(text),
+            _ => runs.push((text.to_string(), style)),
+        }
+    }
+
+    let rendered: String = runs
+        .iter()
+        .map(|(text, style)| match style {
+            Style::Changed => color(text).bold().reversed().to_string(),
+            Style::Invisible => color(text).dimmed().to_string(),
+            Style::Normal => color(text).to_string(),
+        })
+        .collect();
+    println!("{} {} {}", format!("{:>4} |", index + 1).blue(), color(sign), rendered);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Style {
+    Normal,
+    Changed,
+    Invisible,
+}
+
+fn line_list(lines: &[usize]) -> String {
+    lines.iter().map(|line| line.to_string()).collect::<Vec<_>>().join(", ")
+}
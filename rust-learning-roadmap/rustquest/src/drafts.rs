@@ -0,0 +1,94 @@
+// Drafts - Learner Code Kept Between Attempts
+// ===========================================
+
+use crate::exercises::Exercise;
+use serde_json::{json, Value};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+const DRAFTS_DIR: &str = "drafts";
+
+/// Konfigurasi rust-analyzer untuk folder draft. Draft bukan bagian dari
+/// project Cargo, jadi tanpa file ini editor tidak memberi diagnostic,
+/// completion, atau go-to-definition.
+const PROJECT_FILE: &str = "rust-project.json";
+
+/// Lokasi draft untuk sebuah exercise, misal `drafts/1.1.1.rs`.
+pub fn draft_path(exercise_id: &str) -> PathBuf {
+    let name: String = exercise_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    PathBuf::from(DRAFTS_DIR).join(format!("{}.rs", name))
+}
+
+/// Pastikan draft exercise ada. Draft baru diisi dengan `code_template`;
+/// draft yang sudah ada tidak disentuh agar percobaan berikutnya
+/// melanjutkan kode terakhir learner. Draft juga didaftarkan di
+/// `drafts/rust-project.json` agar dikenali rust-analyzer.
+pub fn ensure_draft(exercise: &Exercise) -> io::Result<PathBuf> {
+    let path = draft_path(&exercise.id);
+    if !path.exists() {
+        fs::create_dir_all(DRAFTS_DIR)?;
+        fs::write(&path, &exercise.code_template)?;
+    }
+    register_crate(exercise, &path)?;
+    Ok(path)
+}
+
+/// Tambahkan draft sebagai crate tersendiri di `rust-project.json`, dengan
+/// edition exercise. Crate draft lain yang sudah terdaftar dipertahankan.
+fn register_crate(exercise: &Exercise, path: &Path) -> io::Result<()> {
+    let project_path = Path::new(DRAFTS_DIR).join(PROJECT_FILE);
+    let root_module = path.file_name().map(|f| f.to_string_lossy().to_string()).unwrap_or_default();
+
+    // File yang rusak atau diubah tangan ditulis ulang dari awal
+    let mut crates: Vec<Value> = fs::read_to_string(&project_path)
+        .ok()
+        .and_then(|text| serde_json::from_str::<Value>(&text).ok())
+        .and_then(|project| project["crates"].as_array().cloned())
+        .unwrap_or_default();
+    crates.retain(|entry| entry["root_module"] != root_module.as_str());
+    crates.push(json!({
+        "display_name": exercise.id,
+        "root_module": root_module,
+        "edition": exercise.edition,
+        "deps": [],
+    }));
+    crates.sort_by(|a, b| a["root_module"].as_str().cmp(&b["root_module"].as_str()));
+
+    let mut project = json!({ "crates": crates });
+    if let Some(sysroot) = sysroot() {
+        project["sysroot"] = json!(sysroot);
+    }
+    let text = serde_json::to_string_pretty(&project).map_err(io::Error::other)?;
+    fs::write(project_path, text + "\n")
+}
+
+/// Folder sysroot toolchain, agar rust-analyzer bisa membaca `std`.
+fn sysroot() -> Option<String> {
+    let output = Command::new("rustc").args(["--print", "sysroot"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Editor pilihan learner dari `$VISUAL` atau `$EDITOR`.
+pub fn editor() -> Option<String> {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|key| env::var(key).ok())
+        .find(|value| !value.trim().is_empty())
+}
+
+/// Buka `path` di editor dan tunggu sampai editor ditutup. Nilai editor boleh
+/// berisi argumen, misal `code --wait`.
+pub fn open_in_editor(editor: &str, path: &Path) -> io::Result<ExitStatus> {
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or(editor);
+    Command::new(program).args(parts).arg(path).status()
+}
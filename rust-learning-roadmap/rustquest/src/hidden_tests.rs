@@ -0,0 +1,185 @@
+// Hidden Tests - Behaviour Checks with `rustc --test`
+// ===================================================
+
+use crate::diagnostics::Diagnostic;
+
+/// Nama module tempat test tersembunyi ditempel ke kode learner.
+const MODULE: &str = "hidden_tests";
+
+pub struct TestOutcome {
+    pub name: String,
+    pub passed: bool,
+    pub message: String,
+}
+
+/// Gabungkan kode learner dengan test tersembunyi dari exercise. Test bisa
+/// memanggil fungsi learner langsung lewat `use super::*`.
+pub fn build_source(code: &str, tests: &str) -> String {
+    format!(
+        "{}\n\n#[cfg(test)]\nmod {} {{\n    #![allow(unused_imports)]\n    use super::*;\n\n{}\n}}\n",
+        code, MODULE, tests
+    )
+}
+
+/// Baca hasil setiap test dari output harness libtest, termasuk pesan panic
+/// untuk test yang gagal.
+pub fn parse_results(stdout: &str) -> Vec<TestOutcome> {
+    let mut outcomes: Vec<TestOutcome> = stdout
+        .lines()
+        .filter_map(|line| {
+            let rest = line.strip_prefix("test ")?;
+            let (name, status) = rest.rsplit_once(" ... ")?;
+            let passed = match status.trim() {
+                "ok" => true,
+                "FAILED" => false,
+                _ => return None,
+            };
+            Some(TestOutcome {
+                name: short_name(name).to_string(),
+                passed,
+                message: String::new(),
+            })
+        })
+        .collect();
+
+    for outcome in outcomes.iter_mut().filter(|o| !o.passed) {
+        outcome.message = failure_message(stdout, &outcome.name);
+    }
+    outcomes
+}
+
+/// Apakah harness berjalan sampai selesai: ada ringkasan `test result:`
+/// dan jumlah hasil sama dengan jumlah di `running N tests`. Harness yang
+/// keluar di tengah jalan bisa saja punya exit code 0.
+pub fn completed(stdout: &str) -> bool {
+    let running: Option<usize> = stdout.lines().find_map(|line| {
+        line.strip_prefix("running ")?
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()
+    });
+    let finished = stdout.lines().any(|line| line.starts_with("test result: "));
+    finished && running == Some(parse_results(stdout).len())
+}
+
+/// Sembunyikan isi test dari diagnostic `source` hasil `build_source`:
+/// span di module test dibuang, dan error yang berasal dari module test
+/// diganti pesan umum agar assertion-nya tidak terlihat. Diagnostic dari
+/// source tanpa test tersembunyi dikembalikan apa adanya.
+pub fn redact(errors: &[Diagnostic], source: &str) -> Vec<Diagnostic> {
+    let first_hidden = module_line(source);
+    errors
+        .iter()
+        .map(|error| {
+            let mut error = error.clone();
+            let in_tests = error
+                .spans
+                .iter()
+                .any(|span| span.is_primary && span.line_start >= first_hidden);
+            error.spans.retain(|span| span.line_end < first_hidden);
+            if in_tests {
+                error.message = "test tersembunyi tidak bisa di-compile terhadap signature kamu".to_string();
+                error.children.clear();
+            }
+            error
+        })
+        .collect()
+}
+
+/// Nomor baris `#[cfg(test)]` module test tersembunyi, atau `usize::MAX`
+/// jika `source` tidak berisi test tersembunyi.
+fn module_line(source: &str) -> usize {
+    let header = format!("\n#[cfg(test)]\nmod {} {{", MODULE);
+    source
+        .rfind(&header)
+        .map_or(usize::MAX, |at| source[..at].matches('\n').count() + 2)
+}
+
+fn short_name(name: &str) -> &str {
+    name.strip_prefix(MODULE)
+        .and_then(|n| n.strip_prefix("::"))
+        .unwrap_or(name)
+}
+
+/// Ambil isi bagian `---- hidden_tests::nama stdout ----` dari output
+/// harness.
+fn failure_message(stdout: &str, name: &str) -> String {
+    let header = format!("---- {}::{} stdout ----", MODULE, name);
+    let Some(start) = stdout.find(&header) else {
+        return String::new();
+    };
+    stdout[start + header.len()..]
+        .lines()
+        .skip_while(|line| line.trim().is_empty())
+        .take_while(|line| !line.starts_with("---- ") && !line.starts_with("failures:"))
+        .filter(|line| !line.starts_with("thread '") && !line.starts_with("note: run with"))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSED: &str = "\nrunning 2 tests\n\
+                          test hidden_tests::satu ... ok\n\
+                          test hidden_tests::dua ... ok\n\n\
+                          test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s\n\n";
+
+    const FAILED: &str = "\nrunning 2 tests\n\
+                          test hidden_tests::dua ... FAILED\n\
+                          test hidden_tests::satu ... ok\n\n\
+                          failures:\n\n\
+                          ---- hidden_tests::dua stdout ----\n\n\
+                          thread 'hidden_tests::dua' (27048) panicked at main.rs:6:24:\n\
+                          assertion `left == right` failed\n  left: 2\n right: 3\n\
+                          note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n\n\n\
+                          failures:\n    hidden_tests::dua\n\n\
+                          test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s\n\n";
+
+    #[test]
+    fn parse_passing_run() {
+        let outcomes = parse_results(PASSED);
+        let names: Vec<&str> = outcomes.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, ["satu", "dua"]);
+        assert!(outcomes.iter().all(|o| o.passed && o.message.is_empty()));
+        assert!(completed(PASSED));
+    }
+
+    #[test]
+    fn parse_failing_run_with_message() {
+        let outcomes = parse_results(FAILED);
+        assert_eq!(outcomes.len(), 2);
+        let failed = &outcomes[0];
+        assert_eq!(failed.name, "dua");
+        assert!(!failed.passed);
+        assert_eq!(failed.message, "assertion `left == right` failed\n  left: 2\n right: 3");
+        assert!(outcomes[1].passed);
+        assert!(completed(FAILED));
+    }
+
+    #[test]
+    fn truncated_run_is_not_completed() {
+        // Program learner memanggil `process::exit(0)` di tengah test
+        let stdout = "\nrunning 3 tests\ntest hidden_tests::satu ... ok\n";
+        assert_eq!(parse_results(stdout).len(), 1);
+        assert!(!completed(stdout));
+        assert!(!completed(""));
+    }
+
+    #[test]
+    fn summary_without_all_results_is_not_completed() {
+        // Learner mencetak ringkasan palsu sebelum keluar
+        let stdout = "\nrunning 2 tests\ntest hidden_tests::satu ... ok\n\ntest result: ok. 2 passed\n";
+        assert!(!completed(stdout));
+    }
+
+    #[test]
+    fn results_without_summary_are_not_completed() {
+        let stdout = "\nrunning 1 test\ntest hidden_tests::satu ... ok\n";
+        assert!(!completed(stdout));
+    }
+}
@@ -0,0 +1,224 @@
+// Submission History - Every Attempt per Exercise
+// ===============================================
+
+use crate::diff;
+use crate::exercises::{Exercise, Quest};
+use crate::progress::Progress;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Satu kali submit kode untuk sebuah exercise.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    /// Waktu submit dalam detik sejak Unix epoch (UTC).
+    pub timestamp: u64,
+    pub code: String,
+    pub verdict: AttemptVerdict,
+    /// Alasan singkat hasil submit, misal "Output tidak sesuai".
+    pub summary: String,
+    /// Ringkasan satu baris untuk setiap error compile.
+    #[serde(default)]
+    pub diagnostics: Vec<String>,
+    /// Output program (stdout) dari case yang ditampilkan ke learner.
+    #[serde(default)]
+    pub output: String,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptVerdict {
+    Passed,
+    CompileError,
+    Crashed,
+    Failed,
+}
+
+impl Attempt {
+    pub fn new(code: &str, verdict: AttemptVerdict, summary: String) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Attempt {
+            timestamp,
+            code: code.to_string(),
+            verdict,
+            summary,
+            diagnostics: Vec::new(),
+            output: String::new(),
+        }
+    }
+
+    fn status(&self) -> String {
+        match self.verdict {
+            AttemptVerdict::Passed => "✅ Lulus".green().to_string(),
+            AttemptVerdict::CompileError => "🛑 Compile error".red().to_string(),
+            AttemptVerdict::Crashed => "💥 Crash".red().to_string(),
+            AttemptVerdict::Failed => "❌ Gagal".red().to_string(),
+        }
+    }
+}
+
+/// Menu riwayat: pilih exercise, lalu lihat atau bandingkan percobaannya.
+pub fn run_menu(progress: &Progress, quests: &[Quest]) {
+    let exercises: Vec<&Exercise> = quests
+        .iter()
+        .flat_map(|quest| &quest.exercises)
+        .filter(|exercise| progress.history.contains_key(&exercise.id))
+        .collect();
+
+    if exercises.is_empty() {
+        println!("{}", "ℹ️  Belum ada riwayat submit. Kerjakan quest dulu!".yellow());
+        return;
+    }
+
+    loop {
+        println!("\n{}", "═══════════════════════════════════════".magenta());
+        println!("{}", "📜 RIWAYAT SUBMIT".magenta().bold());
+        println!("{}", "═══════════════════════════════════════".magenta());
+        for (i, exercise) in exercises.iter().enumerate() {
+            let attempts = progress.history[&exercise.id].len();
+            println!("  {}. Exercise {} ({} percobaan)", i + 1, exercise.id, attempts);
+        }
+        println!("  0. ⬅️  Kembali");
+
+        let Some(choice) = prompt_number("Pilih exercise: ") else {
+            println!("{}", "❌ Pilihan tidak valid!".red());
+            continue;
+        };
+        if choice == 0 {
+            return;
+        }
+        match exercises.get(choice - 1) {
+            Some(exercise) => exercise_menu(
+                &exercise.id,
+                &exercise.code_template,
+                &progress.history[&exercise.id],
+            ),
+            None => println!("{}", "❌ Pilihan tidak valid!".red()),
+        }
+    }
+}
+
+fn exercise_menu(id: &str, template: &str, attempts: &[Attempt]) {
+    loop {
+        println!("\n{}", format!("📜 Riwayat exercise {}", id).cyan().bold());
+        for (i, attempt) in attempts.iter().enumerate() {
+            println!(
+                "  #{:<3} {}  {}  {}",
+                i + 1,
+                format_timestamp(attempt.timestamp).white(),
+                attempt.status(),
+                attempt.summary
+            );
+        }
+
+        println!("\n{}", "Pilihan:".yellow());
+        println!("  1. 👁️  Lihat percobaan");
+        println!("  2. 🔀 Bandingkan dua percobaan");
+        println!("  3. 📝 Bandingkan percobaan dengan template");
+        println!("  0. ⬅️  Kembali");
+
+        match prompt_number("Pilih (0-3): ") {
+            Some(0) => return,
+            Some(1) => {
+                if let Some((_, attempt)) = pick_attempt(attempts, "Nomor percobaan: #") {
+                    show_attempt(attempt);
+                }
+            }
+            Some(2) => {
+                let Some((old, first)) = pick_attempt(attempts, "Percobaan lama: #") else {
+                    continue;
+                };
+                let Some((new, second)) = pick_attempt(attempts, "Percobaan baru: #") else {
+                    continue;
+                };
+                diff::print_diff(
+                    &format!("percobaan #{}", old),
+                    &format!("percobaan #{}", new),
+                    &first.code,
+                    &second.code,
+                );
+            }
+            Some(3) => {
+                if let Some((number, attempt)) = pick_attempt(attempts, "Nomor percobaan: #") {
+                    diff::print_diff("template", &format!("percobaan #{}", number), template, &attempt.code);
+                }
+            }
+            _ => println!("{}", "❌ Pilihan tidak valid!".red()),
+        }
+    }
+}
+
+fn show_attempt(attempt: &Attempt) {
+    println!("\n{}", "═══════════════════════════════════════".cyan());
+    println!(
+        "{} {}  {}",
+        "🕒".cyan(),
+        format_timestamp(attempt.timestamp),
+        attempt.status()
+    );
+    println!("{} {}", "Hasil:".yellow(), attempt.summary);
+    println!("{}", "═══════════════════════════════════════".cyan());
+    println!("{}", "```rust".white());
+    println!("{}", attempt.code.trim_end().white());
+    println!("{}", "```".white());
+
+    if !attempt.diagnostics.is_empty() {
+        println!("{}", "Error compile:".red().bold());
+        for diagnostic in &attempt.diagnostics {
+            println!("  • {}", diagnostic.red());
+        }
+    }
+    if !attempt.output.is_empty() {
+        println!("{}", "📤 Output:".cyan().bold());
+        println!("{}", attempt.output.trim_end().white());
+    }
+}
+
+/// Minta nomor percobaan (mulai dari 1); kembalikan nomor dan percobaannya.
+fn pick_attempt<'a>(attempts: &'a [Attempt], label: &str) -> Option<(usize, &'a Attempt)> {
+    let picked = prompt_number(label)
+        .filter(|&n| n >= 1)
+        .and_then(|n| attempts.get(n - 1).map(|attempt| (n, attempt)));
+    if picked.is_none() {
+        println!("{}", "❌ Nomor percobaan tidak ada!".red());
+    }
+    picked
+}
+
+fn prompt_number(label: &str) -> Option<usize> {
+    print!("{}", label.yellow());
+    io::stdout().flush().unwrap();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    input.trim().parse().ok()
+}
+
+/// Format timestamp sebagai `YYYY-MM-DD HH:MM UTC`.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // Konversi jumlah hari ke tanggal kalender (algoritma civil_from_days)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds % 3_600 / 60
+    )
+}
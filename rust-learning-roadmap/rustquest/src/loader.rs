@@ -0,0 +1,698 @@
+// Quest Loader - Quest Definitions from TOML/JSON Files
+// =====================================================
+
+use crate::exercises::{Exercise, ExerciseKind, ExpectedExit, Quest};
+use crate::packs;
+use crate::quiz::Quiz;
+use crate::toolchain::RustVersion;
+use colored::Colorize;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Quest bawaan, ikut di-compile ke binary agar RustQuest tetap bisa
+/// dipakai tanpa folder `quests/`.
+const BUILTIN: [(&str, &str); 7] = [
+    ("1.1-variables.toml", include_str!("../quests/1.1-variables.toml")),
+    ("1.2-control_flow.toml", include_str!("../quests/1.2-control_flow.toml")),
+    ("1.3-functions.toml", include_str!("../quests/1.3-functions.toml")),
+    ("1.4-ownership.toml", include_str!("../quests/1.4-ownership.toml")),
+    ("1.7-error_handling.toml", include_str!("../quests/1.7-error_handling.toml")),
+    ("2.7-performance.toml", include_str!("../quests/2.7-performance.toml")),
+    ("4.3-serde.toml", include_str!("../quests/4.3-serde.toml")),
+];
+
+/// Folder quest di working directory.
+const QUESTS_DIR: &str = "quests";
+
+/// Env untuk memakai folder quest lain, misal saat menulis quest baru.
+const QUESTS_ENV: &str = "RUSTQUEST_QUESTS";
+
+const EDITIONS: [&str; 4] = ["2015", "2018", "2021", "2024"];
+
+/// Kesalahan di sebuah file quest beserta lokasinya.
+pub struct LoadError {
+    pub file: String,
+    /// Nomor baris (mulai dari 1), atau 0 jika berlaku untuk seluruh file.
+    pub line: usize,
+    /// Field yang bermasalah, misal `1.1.2 › cases[0].expected`.
+    pub field: String,
+    pub message: String,
+}
+
+impl LoadError {
+    /// Kesalahan yang berlaku untuk seluruh file, misal file tidak bisa dibaca.
+    pub fn file(file: &str, message: String) -> Self {
+        LoadError {
+            file: file.to_string(),
+            line: 0,
+            field: String::new(),
+            message,
+        }
+    }
+
+    /// Kesalahan di key `key` pertama di `text` (baris 0 jika tidak ada).
+    pub fn at_key(file: &str, text: &str, key: &str, message: String) -> Self {
+        LoadError {
+            file: file.to_string(),
+            line: find_key(text, 0..text.len(), key).map_or(0, |at| line_at(text, at)),
+            field: key.to_string(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file)?;
+        if self.line > 0 {
+            write!(f, ":{}", self.line)?;
+        }
+        if !self.field.is_empty() {
+            write!(f, " ({})", self.field)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Muat quest bawaan, semua file `.toml`/`.json` di folder quest, lalu
+/// quest dari pack yang terpasang. Quest di folder dengan id yang sama
+/// menggantikan quest bawaan; quest dengan id baru ditambahkan di belakang.
+/// Semua kesalahan dikumpulkan, file yang rusak tidak dilewati diam-diam.
+pub fn load() -> Result<Vec<Quest>, Vec<LoadError>> {
+    let mut loaded: Vec<Loaded> = Vec::new();
+    let mut errors = Vec::new();
+
+    for (name, text) in BUILTIN {
+        match parse_quest(&format!("<bawaan>/{}", name), text) {
+            Ok(quest) => loaded.push(Loaded { quest, file: name.to_string(), builtin: true }),
+            Err(mut e) => errors.append(&mut e),
+        }
+    }
+
+    let dir = quests_dir();
+    for path in quest_files(&dir, &mut errors) {
+        let (file, text) = match read(&path) {
+            Ok(read) => read,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+        let quest = match parse_quest(&file, &text) {
+            Ok(quest) => quest,
+            Err(mut e) => {
+                errors.append(&mut e);
+                continue;
+            }
+        };
+
+        match loaded.iter().position(|l| l.quest.id == quest.id) {
+            Some(i) if loaded[i].builtin => loaded[i] = Loaded { quest, file, builtin: false },
+            Some(i) => errors.push(LoadError::at_key(
+                &file,
+                &text,
+                "id",
+                format!("quest `{}` sudah didefinisikan di {}", quest.id, loaded[i].file),
+            )),
+            None => loaded.push(Loaded { quest, file, builtin: false }),
+        }
+    }
+
+    for dir in packs::installed_dirs() {
+        match packs::load(&dir) {
+            Ok(pack) => loaded.extend(pack.quests.into_iter().map(|quest| Loaded {
+                quest,
+                file: pack.manifest.name.clone(),
+                builtin: false,
+            })),
+            Err(mut e) => errors.append(&mut e),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(loaded.into_iter().map(|l| l.quest).collect())
+    } else {
+        Err(errors)
+    }
+}
+
+pub fn print_errors(errors: &[LoadError]) {
+    println!("{}", "❌ File quest tidak valid:".red().bold());
+    for error in errors {
+        println!("  {} {}", "•".red(), error);
+    }
+    println!(
+        "\n{}",
+        "Perbaiki file di atas, lalu jalankan ulang. Pack yang rusak bisa dihapus dengan `rustquest pack remove <nama>`."
+            .yellow()
+    );
+}
+
+/// Baca file quest atau manifest. Mengembalikan nama file untuk pesan
+/// error beserta isinya.
+pub fn read(path: &Path) -> Result<(String, String), LoadError> {
+    let file = path.display().to_string();
+    match fs::read_to_string(path) {
+        Ok(text) => Ok((file, text)),
+        Err(e) => Err(LoadError::file(&file, format!("gagal dibaca: {}", e))),
+    }
+}
+
+/// Deserialize `text` sebagai JSON (file `.json`) atau TOML, dengan lokasi
+/// kesalahan yang tepat.
+pub fn deserialize<T: DeserializeOwned>(file: &str, text: &str) -> Result<T, LoadError> {
+    if file.ends_with(".json") {
+        serde_json::from_str(text).map_err(|e| json_error(file, text, &e))
+    } else {
+        toml::from_str(text).map_err(|e| toml_error(file, text, &e))
+    }
+}
+
+/// Parse dan validasi satu file quest.
+pub fn parse_quest(file: &str, text: &str) -> Result<Quest, Vec<LoadError>> {
+    let quest: Quest = deserialize(file, text).map_err(|e| vec![e])?;
+    let errors = validate(&quest, file, text);
+    if errors.is_empty() {
+        Ok(quest)
+    } else {
+        Err(errors)
+    }
+}
+
+struct Loaded {
+    quest: Quest,
+    file: String,
+    builtin: bool,
+}
+
+fn quests_dir() -> PathBuf {
+    env::var_os(QUESTS_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(QUESTS_DIR))
+}
+
+/// File quest di `dir`, urut nama. Folder default yang tidak ada bukan
+/// kesalahan; folder dari `$RUSTQUEST_QUESTS` yang tidak ada adalah
+/// kesalahan.
+fn quest_files(dir: &Path, errors: &mut Vec<LoadError>) -> Vec<PathBuf> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            if env::var_os(QUESTS_ENV).is_some() {
+                errors.push(LoadError::file(
+                    &dir.display().to_string(),
+                    format!("folder quest ({}) tidak bisa dibaca: {}", QUESTS_ENV, e),
+                ));
+            }
+            return Vec::new();
+        }
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| matches!(path.extension().and_then(|e| e.to_str()), Some("toml" | "json")))
+        .collect();
+    files.sort();
+    files
+}
+
+fn toml_error(file: &str, text: &str, error: &toml::de::Error) -> LoadError {
+    let offset = error.span().map_or(0, |span| span.start);
+    // Pesan sintaks toml bisa beberapa baris, misal "invalid table header\nexpected newline"
+    let message = error.message().trim().replace('\n', "; ");
+    let key = field_in_message(&message).or_else(|| key_before(text, offset));
+    let path = [current_table(&text[..line_end(text, offset)]), key.unwrap_or_default()]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(".");
+    LoadError {
+        file: file.to_string(),
+        line: line_at(text, offset),
+        field: with_exercise(text, offset, path),
+        message,
+    }
+}
+
+fn json_error(file: &str, text: &str, error: &serde_json::Error) -> LoadError {
+    let offset = offset_of(text, error.line(), error.column());
+    // Pesan serde_json diakhiri " at line X column Y"; lokasi ditulis sendiri
+    let full = error.to_string();
+    let message = full.rsplit_once(" at line ").map_or(full.as_str(), |(m, _)| m);
+    let path = field_in_message(message)
+        .or_else(|| key_before(text, offset))
+        .unwrap_or_default();
+    LoadError {
+        file: file.to_string(),
+        line: error.line(),
+        field: with_exercise(text, offset, path),
+        message: message.to_string(),
+    }
+}
+
+/// Nama field dari pesan seperti "missing field `expected`" atau
+/// "unknown field `hintz`, expected one of ...".
+fn field_in_message(message: &str) -> Option<String> {
+    let re = Regex::new(r"field `([^`]+)`").unwrap();
+    re.captures(message).map(|c| c[1].to_string())
+}
+
+/// Key terakhir sebelum `offset` di baris yang sama, misal `edition` dari
+/// `edition = "2022"` atau `"edition": "2022"`. Jika `offset` menunjuk
+/// awal baris, key di baris itu yang dipakai.
+fn key_before(text: &str, offset: usize) -> Option<String> {
+    let start = line_start(text, offset);
+    let re = Regex::new(r#""?([A-Za-z0-9_.-]+)"?\s*[=:]"#).unwrap();
+    re.captures_iter(&text[start..offset.min(text.len())])
+        .last()
+        .or_else(|| re.captures(&text[start..line_end(text, offset)]))
+        .map(|c| c[1].to_string())
+}
+
+/// Header tabel TOML terakhir di `text`, misal `exercises.cases`. Baris di
+/// dalam string multi-baris (kode, isi `Cargo.toml` template) diabaikan.
+fn current_table(text: &str) -> String {
+    let header = Regex::new(r"^\s*\[\[?\s*([A-Za-z0-9_.-]+)\s*\]\]?\s*(#.*)?$").unwrap();
+    let mut table = String::new();
+    let mut open: Option<&str> = None;
+    for line in text.lines() {
+        if open.is_none() {
+            if let Some(c) = header.captures(line) {
+                table = c[1].to_string();
+            }
+        }
+        let mut rest = line;
+        loop {
+            let next = match open {
+                Some(delim) => rest.find(delim).map(|i| (i, delim)),
+                None => ["'''", "\"\"\""]
+                    .into_iter()
+                    .filter_map(|d| rest.find(d).map(|i| (i, d)))
+                    .min(),
+            };
+            let Some((i, delim)) = next else {
+                break;
+            };
+            rest = &rest[i + delim.len()..];
+            open = if open.is_some() { None } else { Some(delim) };
+        }
+    }
+    table
+}
+
+/// Tambahkan id exercise terdekat sebelum `offset` (jika ada) ke path
+/// field, agar jelas exercise mana yang bermasalah.
+fn with_exercise(text: &str, offset: usize, path: String) -> String {
+    let id = Regex::new(r#"(?m)^\s*"?id"?\s*[=:]\s*"([^"]+)""#).unwrap();
+    let before = &text[..line_end(text, offset)];
+    match id.captures_iter(before).last() {
+        // Id pertama adalah id quest, bukan exercise
+        Some(c) if c.get(0).map(|m| m.start()) != id.find(text).map(|m| m.start()) => {
+            // `exercises.cases.expected` cukup ditulis `cases.expected`
+            let rest = path.strip_prefix("exercises").unwrap_or(&path).trim_start_matches('.');
+            if rest.is_empty() {
+                c[1].to_string()
+            } else {
+                format!("{} › {}", &c[1], rest)
+            }
+        }
+        _ => path,
+    }
+}
+
+fn line_at(text: &str, offset: usize) -> usize {
+    text[..offset.min(text.len())].matches('\n').count() + 1
+}
+
+fn line_start(text: &str, offset: usize) -> usize {
+    text[..offset.min(text.len())].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn line_end(text: &str, offset: usize) -> usize {
+    let offset = offset.min(text.len());
+    text[offset..].find('\n').map_or(text.len(), |i| offset + i)
+}
+
+/// Byte offset dari posisi `line`/`column` (keduanya mulai dari 1).
+fn offset_of(text: &str, line: usize, column: usize) -> usize {
+    let start: usize = text
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    (start + column.saturating_sub(1)).min(text.len())
+}
+
+/// Posisi key `key` pertama di `range`, baik sebagai key TOML
+/// (`key =`, `key.sub =`, `{ key =`) maupun key JSON (`"key":`).
+fn find_key(text: &str, range: Range<usize>, key: &str) -> Option<usize> {
+    let pattern = format!(r#"(?m)(?:^|[{{,])[ \t]*"?{}"?[ \t]*[=.:]"#, regex::escape(key));
+    let re = Regex::new(&pattern).unwrap();
+    re.find(&text[range.clone()]).map(|m| range.start + m.end() - 1)
+}
+
+fn validate(quest: &Quest, file: &str, text: &str) -> Vec<LoadError> {
+    let mut errors = Vec::new();
+    if quest.exercises.is_empty() {
+        errors.push(LoadError {
+            file: file.to_string(),
+            line: find_key(text, 0..text.len(), "id").map_or(0, |at| line_at(text, at)),
+            field: "exercises".to_string(),
+            message: "quest harus punya minimal satu exercise".to_string(),
+        });
+    }
+
+    // Setiap exercise dicari lewat id-nya di teks file, untuk nomor baris
+    let mut starts = Vec::new();
+    let mut from = 0;
+    for exercise in &quest.exercises {
+        let quoted = format!("\"{}\"", exercise.id);
+        let found = text[from..].find(&quoted).map_or(from, |i| from + i);
+        // Mulai dari awal baris agar key `id` ikut masuk bagian exercise ini
+        starts.push(line_start(text, found).max(from));
+        from = found + quoted.len();
+    }
+
+    for (i, exercise) in quest.exercises.iter().enumerate() {
+        let end = starts.get(i + 1).copied().unwrap_or(text.len());
+        let mut check = Check {
+            file,
+            text,
+            exercise,
+            range: starts[i]..end,
+            errors: &mut errors,
+        };
+        check.exercise(&quest.id);
+    }
+    errors
+}
+
+struct Check<'a> {
+    file: &'a str,
+    text: &'a str,
+    exercise: &'a Exercise,
+    /// Bagian file yang berisi exercise ini.
+    range: Range<usize>,
+    errors: &'a mut Vec<LoadError>,
+}
+
+impl Check<'_> {
+    fn exercise(&mut self, quest_id: &str) {
+        let exercise = self.exercise;
+
+        if !exercise.id.starts_with(&format!("{}.", quest_id)) {
+            self.fail(None, "id", "id", format!("id exercise harus diawali id quest `{}.`", quest_id));
+        }
+        if !EDITIONS.contains(&exercise.edition.as_str()) {
+            self.fail(None, "edition", "edition", format!(
+                "edition `{}` tidak dikenal (pilih {})",
+                exercise.edition,
+                EDITIONS.join(", ")
+            ));
+        }
+        if let Some(version) = &exercise.min_rustc {
+            if RustVersion::parse(version).is_none() {
+                self.fail(None, "min_rustc", "min_rustc", format!(
+                    "versi `{}` tidak valid, tulis seperti `1.65`",
+                    version
+                ));
+            }
+        }
+
+        self.cases();
+        self.kind();
+
+        if let Some(budget) = &exercise.performance {
+            if budget.runs == 0 {
+                self.fail(None, "runs", "performance.runs", "minimal 1 run".to_string());
+            }
+            if !matches!(exercise.kind, ExerciseKind::SingleFile {}) {
+                self.fail(None, "performance", "performance", "budget performa hanya untuk exercise single_file".to_string());
+            }
+            if exercise.cases.is_empty() {
+                self.fail(None, "performance", "performance", "budget performa butuh minimal satu case sebagai input".to_string());
+            }
+        }
+    }
+
+    fn cases(&mut self) {
+        let mut from = self.range.start;
+        for (i, case) in self.exercise.cases.iter().enumerate() {
+            // Setiap case punya tepat satu `expected`, jadi dipakai sebagai penanda
+            let at = self.find_from(from, "expected");
+            if let Some(at) = at {
+                from = at + 1;
+            }
+            let field = |name: &str| format!("cases[{}].{}", i, name);
+
+            if let Err(message) = case.expected.validate() {
+                self.fail(at, "expected", &field("expected"), message);
+            }
+            if let Some(Err(message)) = case.stderr.as_ref().map(|m| m.validate()) {
+                let at = at.and_then(|at| self.find_from(at, "stderr"));
+                self.fail(at, "stderr", &field("stderr"), message);
+            }
+            if let ExpectedExit::Panic(matcher) = &case.exit {
+                if let Err(message) = matcher.validate() {
+                    let at = at.and_then(|at| self.find_from(at, "exit"));
+                    self.fail(at, "exit", &field("exit.panic"), message);
+                }
+            }
+        }
+    }
+
+    fn kind(&mut self) {
+        let exercise = self.exercise;
+        let needs_checks = match &exercise.kind {
+            ExerciseKind::SingleFile {} | ExerciseKind::FixBug {} => true,
+            ExerciseKind::Cargo(project) => {
+                if !project.files.iter().any(|(path, _)| path == "Cargo.toml") {
+                    self.fail(None, "files", "kind.files", "project Cargo harus punya file `Cargo.toml`".to_string());
+                }
+                for (path, _) in &project.files {
+                    if !packs::is_inside(path) {
+                        self.fail(None, "files", "kind.files", format!(
+                            "path `{}` harus relatif dan berada di dalam project",
+                            path
+                        ));
+                    }
+                }
+                true
+            }
+            ExerciseKind::CompileFail(expected) => {
+                if !Regex::new(r"^E\d{4}$").unwrap().is_match(&expected.code) {
+                    self.fail(None, "code", "kind.code", format!(
+                        "error code `{}` tidak valid, tulis seperti `E0382`",
+                        expected.code
+                    ));
+                }
+                false
+            }
+            ExerciseKind::PredictError { .. } => {
+                if exercise.code_template.trim().is_empty() {
+                    self.fail(None, "code_template", "code_template", "kode yang ditebak error-nya tidak boleh kosong".to_string());
+                }
+                false
+            }
+            ExerciseKind::Quiz(quiz) => {
+                self.quiz(quiz);
+                false
+            }
+        };
+
+        if needs_checks && exercise.cases.is_empty() && exercise.hidden_tests.is_none() {
+            self.fail(None, "id", "cases", "exercise kode harus punya minimal satu case atau hidden_tests".to_string());
+        }
+    }
+
+    fn quiz(&mut self, quiz: &Quiz) {
+        let count = quiz.options().len();
+        if matches!(quiz, Quiz::MultipleChoice { .. } | Quiz::MultiSelect { .. }) && count < 2 {
+            self.fail(None, "options", "kind.options", "minimal dua pilihan".to_string());
+            return;
+        }
+        match quiz {
+            Quiz::MultipleChoice { answer, .. } => {
+                if *answer >= count {
+                    self.fail(None, "answer", "kind.answer", out_of_range(*answer, count));
+                }
+            }
+            Quiz::MultiSelect { answers, .. } => {
+                if answers.is_empty() {
+                    self.fail(None, "answers", "kind.answers", "minimal satu jawaban benar".to_string());
+                }
+                if let Some(&answer) = answers.iter().find(|&&a| a >= count) {
+                    self.fail(None, "answers", "kind.answers", out_of_range(answer, count));
+                }
+            }
+            Quiz::ShortAnswer { accepted } => {
+                if accepted.iter().all(|a| a.trim().is_empty()) {
+                    self.fail(None, "accepted", "kind.accepted", "minimal satu jawaban yang diterima".to_string());
+                }
+            }
+            Quiz::PredictOutput { expected } => {
+                if let Err(message) = expected.validate() {
+                    self.fail(None, "expected", "kind.expected", message);
+                }
+                if self.exercise.code_template.trim().is_empty() {
+                    self.fail(None, "code_template", "code_template", "kode yang ditebak output-nya tidak boleh kosong".to_string());
+                }
+            }
+        }
+    }
+
+    fn find_from(&self, from: usize, key: &str) -> Option<usize> {
+        find_key(self.text, from..self.range.end, key)
+    }
+
+    /// Catat kesalahan di `at`, atau di key `key` pertama di exercise ini,
+    /// atau di awal exercise jika key tidak ditemukan (misal nilai default).
+    fn fail(&mut self, at: Option<usize>, key: &str, field: &str, message: String) {
+        let at = at
+            .or_else(|| self.find_from(self.range.start, key))
+            .unwrap_or(self.range.start);
+        self.errors.push(LoadError {
+            file: self.file.to_string(),
+            line: line_at(self.text, at),
+            field: format!("{} › {}", self.exercise.id, field),
+            message,
+        });
+    }
+}
+
+fn out_of_range(index: usize, count: usize) -> String {
+    format!(
+        "index jawaban {} di luar pilihan (0 sampai {})",
+        index,
+        count.saturating_sub(1)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = r#"id = "9.1"
+name = "Uji"
+description = "d"
+hint = "h"
+
+[[exercises]]
+id = "9.1.1"
+question = "q"
+code_template = '''
+fn main() {}
+'''
+
+[[exercises.cases]]
+expected.exact = "ok"
+"#;
+
+    const JSON: &str = r#"{
+  "id": "9.1",
+  "name": "Uji",
+  "description": "d",
+  "hint": "h",
+  "exercises": [
+    {
+      "id": "9.1.1",
+      "question": "q",
+      "cases": [{ "expected": { "exact": "ok" } }]
+    }
+  ]
+}
+"#;
+
+    /// Satu-satunya kesalahan saat `text` di-load sebagai `file`.
+    fn single_error(file: &str, text: &str) -> LoadError {
+        match parse_quest(file, text) {
+            Ok(_) => panic!("quest seharusnya ditolak"),
+            Err(mut errors) => {
+                let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+                assert_eq!(errors.len(), 1, "{:?}", messages);
+                errors.remove(0)
+            }
+        }
+    }
+
+    #[test]
+    fn valid_quests_load() {
+        assert!(parse_quest("uji.toml", TOML).is_ok());
+        assert!(parse_quest("uji.json", JSON).is_ok());
+    }
+
+    #[test]
+    fn toml_unknown_field_points_at_its_line() {
+        let text = TOML.replace("code_template", "hintz = []\ncode_template");
+        let error = single_error("uji.toml", &text);
+        assert_eq!(error.file, "uji.toml");
+        assert_eq!(error.line, 9);
+        assert_eq!(error.field, "9.1.1 › hintz");
+        assert!(error.message.starts_with("unknown field `hintz`"), "{}", error.message);
+    }
+
+    #[test]
+    fn toml_type_error_inside_array_table() {
+        let text = TOML.replace("[[exercises.cases]]\n", "[[exercises.cases]]\nstdin = 5\n");
+        let error = single_error("uji.toml", &text);
+        assert_eq!(error.line, 14);
+        assert_eq!(error.field, "9.1.1 › cases.stdin");
+    }
+
+    #[test]
+    fn toml_header_inside_multiline_string_is_not_a_table() {
+        // `[dependencies]` di dalam string kode tidak mengganti tabel aktif
+        let text = TOML
+            .replace("fn main() {}\n", "[dependencies]\nfn main() {}\n")
+            .replace("code_template", "hintz = []\ncode_template");
+        let error = single_error("uji.toml", &text);
+        assert_eq!(error.field, "9.1.1 › hintz");
+    }
+
+    #[test]
+    fn toml_validation_error_points_at_key() {
+        let text = TOML.replace("question = \"q\"", "question = \"q\"\nedition = \"2022\"");
+        let error = single_error("uji.toml", &text);
+        assert_eq!(error.line, 9);
+        assert_eq!(error.field, "9.1.1 › edition");
+        assert!(error.message.contains("2022"), "{}", error.message);
+    }
+
+    #[test]
+    fn toml_invalid_regex_points_at_matcher() {
+        let text = TOML.replace("expected.exact = \"ok\"", "expected.regex = \"(\"");
+        let error = single_error("uji.toml", &text);
+        assert_eq!(error.line, 14);
+        assert_eq!(error.field, "9.1.1 › cases[0].expected");
+        assert!(error.message.starts_with("regex tidak valid"), "{}", error.message);
+    }
+
+    #[test]
+    fn json_unknown_field_points_at_its_line() {
+        let text = JSON.replace("\"question\": \"q\",", "\"question\": \"q\",\n      \"hintz\": [],");
+        let error = single_error("uji.json", &text);
+        assert_eq!(error.line, 10);
+        assert_eq!(error.field, "9.1.1 › hintz");
+        assert!(!error.message.contains(" at line "), "{}", error.message);
+    }
+
+    #[test]
+    fn json_type_error_names_the_key() {
+        let text = JSON.replace("\"question\": \"q\",", "\"question\": \"q\",\n      \"edition\": 2021,");
+        let error = single_error("uji.json", &text);
+        assert_eq!(error.line, 10);
+        assert_eq!(error.field, "9.1.1 › edition");
+    }
+
+    #[test]
+    fn quest_level_error_has_no_exercise_prefix() {
+        let error = single_error("uji.toml", &TOML.replace("hint = \"h\"", "hint = 1"));
+        assert_eq!(error.line, 4);
+        assert_eq!(error.field, "hint");
+    }
+}
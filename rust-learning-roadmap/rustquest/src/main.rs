@@ -1,14 +1,80 @@
 // RustQuest - Interactive Rust Learning System
 // =============================================
 
+mod blanks;
+mod cache;
+mod cargo_project;
+mod diagnostics;
+mod diff;
+mod drafts;
 mod exercises;
+mod hidden_tests;
+mod history;
+mod loader;
+mod matcher;
+mod packs;
+mod performance;
 mod progress;
+mod quiz;
+mod rules;
 mod runner;
+mod sandbox;
+mod toolchain;
+mod validate;
+mod watch;
+mod workspace;
 
 use colored::Colorize;
+use std::env;
 use std::io::{self, Write};
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    
+    // Perintah pack tidak butuh rustc maupun quest yang valid, agar pack
+    // yang rusak tetap bisa dihapus
+    if args.first().map(String::as_str) == Some("pack") {
+        std::process::exit(packs::run_command(&args[1..]));
+    }
+    
+    let quests = match loader::load() {
+        Ok(quests) => quests,
+        Err(errors) => {
+            loader::print_errors(&errors);
+            std::process::exit(1);
+        }
+    };
+    
+    match args.first().map(String::as_str) {
+        None => run_main_menu(&quests),
+        Some("validate") => {
+            let allow_skip = args[1..].iter().any(|arg| arg == "--allow-skip");
+            std::process::exit(validate::run(&quests, allow_skip));
+        }
+        Some("watch") => {
+            clear_screen();
+            print_banner();
+            let mut progress = progress::Progress::load();
+            watch::run(&mut progress, &quests);
+        }
+        Some(other) => {
+            println!("{} {}", "❌ Perintah tidak dikenal:".red(), other);
+            print_usage();
+            std::process::exit(2);
+        }
+    }
+}
+
+fn print_usage() {
+    println!("\n{}", "Penggunaan:".yellow().bold());
+    println!("  rustquest          Menu interaktif");
+    println!("  rustquest watch    Cek exercise otomatis setiap file disimpan");
+    println!("  rustquest validate Cek solusi referensi dan template semua quest");
+    println!("                     (--allow-skip: terima exercise yang tidak bisa dicek)");
+    packs::print_usage();
+}
+
+fn run_main_menu(quests: &[exercises::Quest]) {
     clear_screen();
     print_banner();
     
@@ -20,24 +86,26 @@ fn main() {
         println!("{}", "═══════════════════════════════════════".cyan());
         println!("  1. 🎯 Mulai Quest");
         println!("  2. 📊 Lihat Progress");
-        println!("  3. 🔄 Reset Progress");
-        println!("  4. ❌ Keluar");
+        println!("  3. 📜 Riwayat Submit");
+        println!("  4. 🔄 Reset Progress");
+        println!("  5. ❌ Keluar");
         println!();
         
-        print!("{}", "Pilih (1-4): ".yellow());
+        print!("{}", "Pilih (1-5): ".yellow());
         io::stdout().flush().unwrap();
         
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
         
         match input.trim() {
-            "1" => run_quest_menu(&mut progress),
-            "2" => progress.show_progress(),
-            "3" => {
+            "1" => run_quest_menu(quests, &mut progress),
+            "2" => progress.show_progress(quests),
+            "3" => history::run_menu(&progress, quests),
+            "4" => {
                 progress.reset();
                 println!("{}", "✅ Progress direset!".green());
             }
-            "4" => {
+            "5" => {
                 println!("{}", "\n👋 Sampai jumpa, Rustacean! 🦀\n".cyan());
                 break;
             }
@@ -46,15 +114,23 @@ fn main() {
     }
 }
 
-fn run_quest_menu(progress: &mut progress::Progress) {
+fn run_quest_menu(quests: &[exercises::Quest], progress: &mut progress::Progress) {
     loop {
         clear_screen();
         println!("\n{}", "═══════════════════════════════════════".green());
         println!("{}", "⚔️  PILIH QUEST".green().bold());
         println!("{}", "═══════════════════════════════════════".green());
         
-        let quests = exercises::get_all_quests();
+        // Quest dikelompokkan per pack (urutan dari loader sudah berkelompok)
+        let has_packs = quests.iter().any(|quest| quest.pack.is_some());
         for (i, quest) in quests.iter().enumerate() {
+            if has_packs && (i == 0 || quest.pack != quests[i - 1].pack) {
+                let group = match &quest.pack {
+                    Some(name) => format!("🧩 Pack: {}", name),
+                    None => "🦀 RustQuest".to_string(),
+                };
+                println!("\n  {}", group.bold());
+            }
             let status = if progress.is_completed(&quest.id) {
                 "✅".to_string()
             } else {
@@ -100,4 +176,9 @@ fn print_banner() {
     ║   © 2024 Rizqi Nur Rifai                              ║
     ╚═══════════════════════════════════════════════════════╝
     "#.cyan().bold());
+    if let Some(toolchain) = toolchain::current() {
+        println!("    {} rustc {} ({})", "🔧 Toolchain:".white(), toolchain.version, toolchain.host);
+    } else {
+        println!("    {} {}", "⚠️  Toolchain:".yellow(), "rustc tidak ditemukan, hanya kuis yang bisa dikerjakan".yellow());
+    }
 }
@@ -0,0 +1,241 @@
+// Output Matchers - How Program Output is Judged
+// ==============================================
+
+use regex::Regex;
+use serde::Deserialize;
+
+/// Cara membandingkan stdout program learner dengan jawaban yang diharapkan.
+/// Setiap exercise memilih sendiri matcher yang paling sesuai.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputMatcher {
+    /// Output harus sama persis (newline di akhir output diabaikan).
+    Exact(String),
+    /// Sama seperti `Exact`, tapi semua whitespace berturut-turut dianggap
+    /// satu spasi.
+    Normalized(String),
+    /// Baris demi baris harus sama dan berurutan; trailing whitespace di
+    /// setiap baris diabaikan.
+    Lines(Vec<String>),
+    /// Seluruh output (setelah di-trim) harus cocok dengan regex ini.
+    Regex(String),
+    /// Seperti `Normalized`, tapi setiap angka boleh berbeda maksimal
+    /// `tolerance` dari angka yang diharapkan.
+    Approx { expected: String, tolerance: f64 },
+    /// Output cukup mengandung teks ini. Longgar, jadi harus dipilih secara
+    /// eksplisit.
+    Contains(String),
+}
+
+impl OutputMatcher {
+    pub fn matches(&self, output: &str) -> bool {
+        match self {
+            OutputMatcher::Exact(expected) => {
+                output.trim_end_matches(['\n', '\r']) == expected.trim_end_matches(['\n', '\r'])
+            }
+            OutputMatcher::Normalized(expected) => normalize(output) == normalize(expected),
+            OutputMatcher::Lines(expected) => {
+                let actual = significant_lines(output);
+                actual.len() == expected.len()
+                    && actual.iter().zip(expected).all(|(a, e)| *a == e.trim_end())
+            }
+            OutputMatcher::Regex(pattern) => match Regex::new(pattern) {
+                Ok(re) => re.is_match(output.trim()),
+                Err(_) => false,
+            },
+            OutputMatcher::Approx { expected, tolerance } => {
+                approx_eq(output, expected, *tolerance)
+            }
+            OutputMatcher::Contains(expected) => output.contains(expected.trim()),
+        }
+    }
+
+    /// Cek matcher dari file quest, misal regex yang tidak valid.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            OutputMatcher::Regex(pattern) => Regex::new(pattern)
+                .map(|_| ())
+                // Pesan parse regex beberapa baris; baris terakhir berisi alasannya
+                .map_err(|e| {
+                    let message = e.to_string();
+                    let reason = message.lines().last().unwrap_or_default().trim_start_matches("error: ");
+                    format!("regex tidak valid: {}", reason)
+                }),
+            OutputMatcher::Approx { tolerance, .. } if tolerance.is_nan() || *tolerance < 0.0 => {
+                Err(format!("tolerance harus >= 0, bukan {}", tolerance))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Teks output yang diharapkan, untuk dibandingkan baris per baris
+    /// dengan output learner. `None` untuk matcher yang berupa pola.
+    pub fn expected_text(&self) -> Option<String> {
+        match self {
+            OutputMatcher::Exact(expected)
+            | OutputMatcher::Normalized(expected)
+            | OutputMatcher::Approx { expected, .. } => Some(expected.clone()),
+            OutputMatcher::Lines(expected) => Some(expected.join("\n")),
+            OutputMatcher::Regex(_) | OutputMatcher::Contains(_) => None,
+        }
+    }
+
+    /// Deskripsi jawaban yang diharapkan untuk ditampilkan ke learner.
+    pub fn describe(&self) -> String {
+        match self {
+            OutputMatcher::Exact(expected) | OutputMatcher::Normalized(expected) => {
+                expected.clone()
+            }
+            OutputMatcher::Lines(expected) => expected.join("\n"),
+            OutputMatcher::Regex(pattern) => format!("(cocok dengan pola) {}", pattern),
+            OutputMatcher::Approx { expected, tolerance } => {
+                format!("{} (toleransi angka ±{})", expected, tolerance)
+            }
+            OutputMatcher::Contains(expected) => format!("(mengandung) {}", expected),
+        }
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn significant_lines(output: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Pecah teks menjadi potongan teks dan angka bergantian, lalu bandingkan
+/// teks secara normalized dan angka dengan toleransi.
+fn approx_eq(output: &str, expected: &str, tolerance: f64) -> bool {
+    let actual = split_numbers(output);
+    let expected = split_numbers(expected);
+    actual.len() == expected.len()
+        && actual.iter().zip(&expected).all(|pair| match pair {
+            (Piece::Number(a), Piece::Number(e)) => (a - e).abs() <= tolerance,
+            (Piece::Text(a), Piece::Text(e)) => a == e,
+            _ => false,
+        })
+}
+
+enum Piece {
+    Text(String),
+    Number(f64),
+}
+
+fn split_numbers(text: &str) -> Vec<Piece> {
+    let number = Regex::new(r"-?\d+(?:\.\d+)?(?:[eE][-+]?\d+)?").unwrap();
+    let mut pieces = Vec::new();
+    let mut last = 0;
+
+    for m in number.find_iter(text) {
+        let between = normalize(&text[last..m.start()]);
+        if !between.is_empty() {
+            pieces.push(Piece::Text(between));
+        }
+        // Regex di atas hanya menangkap angka valid, jadi parse tidak gagal.
+        pieces.push(Piece::Number(m.as_str().parse().unwrap_or(f64::NAN)));
+        last = m.end();
+    }
+
+    let rest = normalize(&text[last..]);
+    if !rest.is_empty() {
+        pieces.push(Piece::Text(rest));
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_ignores_only_trailing_newlines() {
+        let matcher = OutputMatcher::Exact("Halo, Rustacean!".to_string());
+        assert!(matcher.matches("Halo, Rustacean!"));
+        assert!(matcher.matches("Halo, Rustacean!\n"));
+        assert!(matcher.matches("Halo, Rustacean!\r\n"));
+        assert!(!matcher.matches("Halo, Rustacean! \n"));
+        assert!(!matcher.matches(" Halo, Rustacean!"));
+        assert!(!matcher.matches("Halo,  Rustacean!"));
+    }
+
+    #[test]
+    fn normalized_collapses_whitespace() {
+        let matcher = OutputMatcher::Normalized("a b\nc".to_string());
+        assert!(matcher.matches("a   b c"));
+        assert!(matcher.matches("  a\tb\n\nc  \n"));
+        assert!(!matcher.matches("ab c"));
+        assert!(!matcher.matches("a b c d"));
+    }
+
+    #[test]
+    fn lines_ignore_trailing_whitespace_and_final_blank_lines() {
+        let matcher = OutputMatcher::Lines(vec!["satu".to_string(), "dua  ".to_string()]);
+        assert!(matcher.matches("satu\ndua"));
+        assert!(matcher.matches("satu   \ndua\t\n\n\n"));
+        assert!(!matcher.matches(" satu\ndua"));
+        assert!(!matcher.matches("satu\n\ndua"));
+        assert!(!matcher.matches("satu"));
+        assert!(!matcher.matches("satu\ndua\ntiga"));
+    }
+
+    #[test]
+    fn regex_matches_trimmed_output() {
+        let matcher = OutputMatcher::Regex(r"^Hasil: \d+$".to_string());
+        assert!(matcher.matches("Hasil: 42\n"));
+        assert!(matcher.matches("  Hasil: 7  "));
+        assert!(!matcher.matches("Hasil: empat puluh dua"));
+        assert!(!matcher.matches("Hasil: 1\nHasil: 2"));
+    }
+
+    #[test]
+    fn regex_without_anchors_matches_anywhere() {
+        let matcher = OutputMatcher::Regex(r"\d{3}".to_string());
+        assert!(matcher.matches("kode 123 ok"));
+        assert!(!matcher.matches("kode 12 ok"));
+    }
+
+    #[test]
+    fn invalid_regex_never_matches_and_fails_validation() {
+        let matcher = OutputMatcher::Regex("(belum ditutup".to_string());
+        assert!(!matcher.matches("(belum ditutup"));
+        let error = matcher.validate().unwrap_err();
+        assert!(error.starts_with("regex tidak valid: "), "{}", error);
+    }
+
+    #[test]
+    fn approx_allows_numbers_within_tolerance() {
+        let matcher = OutputMatcher::Approx { expected: "Rata-rata: 3.33".to_string(), tolerance: 0.01 };
+        assert!(matcher.matches("Rata-rata: 3.333333"));
+        assert!(matcher.matches("Rata-rata:   3.34\n"));
+        assert!(!matcher.matches("Rata-rata: 3.35"));
+        assert!(!matcher.matches("Rerata: 3.33"));
+        assert!(!matcher.matches("Rata-rata: 3.33 3.33"));
+    }
+
+    #[test]
+    fn approx_handles_negative_and_exponent_numbers() {
+        let matcher = OutputMatcher::Approx { expected: "x = -1.5, y = 2e3".to_string(), tolerance: 0.5 };
+        assert!(matcher.matches("x = -1.2, y = 2000.4"));
+        assert!(!matcher.matches("x = 1.5, y = 2000"));
+    }
+
+    #[test]
+    fn approx_rejects_negative_or_nan_tolerance() {
+        for tolerance in [-0.1, f64::NAN] {
+            let matcher = OutputMatcher::Approx { expected: "1".to_string(), tolerance };
+            assert!(matcher.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn contains_trims_expected_text() {
+        let matcher = OutputMatcher::Contains("  selesai\n".to_string());
+        assert!(matcher.matches("proses...\nselesai!\n"));
+        assert!(!matcher.matches("Selesai"));
+    }
+}
@@ -0,0 +1,389 @@
+// Quest Packs - Installable Third-Party Quests
+// ============================================
+
+use crate::exercises::Quest;
+use crate::loader::{self, LoadError};
+use crate::toolchain::RustVersion;
+use colored::Colorize;
+use regex::Regex;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+/// Manifest di root setiap pack.
+pub const MANIFEST_FILE: &str = "pack.toml";
+
+/// Env untuk menunjuk folder instalasi pack secara manual.
+const PACKS_ENV: &str = "RUSTQUEST_PACKS";
+
+/// Isi `pack.toml`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// Nama pack, juga dipakai sebagai namespace id (`nama/1.1.1`).
+    pub name: String,
+    pub version: String,
+    pub author: String,
+    /// Versi RustQuest minimal, misal `0.1.0`.
+    pub min_rustquest: String,
+    /// File quest, relatif terhadap folder pack.
+    pub quests: Vec<String>,
+}
+
+/// Pack yang sudah di-load. Id quest dan exercise-nya sudah diberi
+/// namespace nama pack agar tidak bentrok dengan quest bawaan.
+pub struct Pack {
+    pub manifest: Manifest,
+    pub quests: Vec<Quest>,
+}
+
+/// Load pack dari folder `dir`: baca manifest, cek versi RustQuest, lalu
+/// parse dan validasi semua file quest yang tercantum.
+pub fn load(dir: &Path) -> Result<Pack, Vec<LoadError>> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    if escapes(dir, &manifest_path) {
+        return Err(vec![LoadError::file(
+            &manifest_path.display().to_string(),
+            "symlink menunjuk ke luar folder pack".to_string(),
+        )]);
+    }
+    let (file, text) = loader::read(&manifest_path).map_err(|e| vec![e])?;
+    let manifest: Manifest = loader::deserialize(&file, &text).map_err(|e| vec![e])?;
+
+    let mut errors = check_manifest(&manifest, &file, &text);
+    let mut quests: Vec<Quest> = Vec::new();
+    for relative in &manifest.quests {
+        if !is_inside(relative) {
+            errors.push(LoadError::at_key(
+                &file,
+                &text,
+                "quests",
+                format!("path `{}` harus relatif dan berada di dalam folder pack", relative),
+            ));
+            continue;
+        }
+        if escapes(dir, &dir.join(relative)) {
+            errors.push(LoadError::at_key(
+                &file,
+                &text,
+                "quests",
+                format!("`{}` adalah symlink ke luar folder pack", relative),
+            ));
+            continue;
+        }
+        let (quest_file, quest_text) = match loader::read(&dir.join(relative)) {
+            Ok(read) => read,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+        match loader::parse_quest(&quest_file, &quest_text) {
+            Ok(quest) if quests.iter().any(|q| q.id == quest.id) => errors.push(LoadError::at_key(
+                &quest_file,
+                &quest_text,
+                "id",
+                format!("quest `{}` muncul lebih dari sekali di pack ini", quest.id),
+            )),
+            Ok(quest) => quests.push(quest),
+            Err(mut e) => errors.append(&mut e),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    for quest in &mut quests {
+        quest.id = format!("{}/{}", manifest.name, quest.id);
+        for exercise in &mut quest.exercises {
+            exercise.id = format!("{}/{}", manifest.name, exercise.id);
+        }
+        quest.pack = Some(manifest.name.clone());
+    }
+    Ok(Pack { manifest, quests })
+}
+
+/// Folder semua pack yang terpasang, urut nama.
+pub fn installed_dirs() -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(packs_dir()) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// Perintah `rustquest pack ...`. Mengembalikan exit code.
+pub fn run_command(args: &[String]) -> i32 {
+    match (args.first().map(String::as_str), args.get(1)) {
+        (Some("install"), Some(source)) => install(Path::new(source)),
+        (Some("list"), None) => {
+            list();
+            0
+        }
+        (Some("remove"), Some(name)) => remove(name),
+        _ => {
+            println!("{}", "Penggunaan:".yellow().bold());
+            print_usage();
+            2
+        }
+    }
+}
+
+pub fn print_usage() {
+    println!("  rustquest pack install <folder|arsip.tar.gz>   Pasang quest pack");
+    println!("  rustquest pack list                            Daftar pack terpasang");
+    println!("  rustquest pack remove <nama>                   Hapus pack");
+}
+
+fn install(source: &Path) -> i32 {
+    // Arsip diekstrak dulu ke folder sementara yang dihapus setelah selesai
+    let extracted;
+    let root = if source.is_dir() {
+        source.to_path_buf()
+    } else {
+        extracted = match extract(source) {
+            Ok(dir) => dir,
+            Err(e) => {
+                println!("{} {}", "❌ Gagal membuka pack:".red(), e);
+                return 1;
+            }
+        };
+        match pack_root(extracted.path()) {
+            Some(root) => root,
+            None => {
+                println!("{} arsip tidak berisi {}", "❌ Pack tidak valid:".red(), MANIFEST_FILE);
+                return 1;
+            }
+        }
+    };
+
+    let pack = match load(&root) {
+        Ok(pack) => pack,
+        Err(errors) => {
+            loader::print_errors(&errors);
+            return 1;
+        }
+    };
+
+    let target = packs_dir().join(&pack.manifest.name);
+    if root.canonicalize().ok() == target.canonicalize().ok() {
+        println!("{} {} sudah terpasang dari folder ini.", "ℹ️  Pack".yellow(), pack.manifest.name);
+        return 0;
+    }
+    let previous = load(&target).ok().map(|old| old.manifest.version);
+    if let Err(e) = copy_pack(&root, &pack.manifest, &target) {
+        println!("{} {}", "❌ Gagal memasang pack:".red(), e);
+        return 1;
+    }
+
+    let manifest = &pack.manifest;
+    match previous {
+        Some(old) => println!(
+            "{} {} diperbarui dari {} ke {} ({} quest).",
+            "✅ Pack".green(),
+            manifest.name.bold(),
+            old,
+            manifest.version,
+            pack.quests.len()
+        ),
+        None => println!(
+            "{} {} {} oleh {} terpasang ({} quest).",
+            "✅ Pack".green(),
+            manifest.name.bold(),
+            manifest.version,
+            manifest.author,
+            pack.quests.len()
+        ),
+    }
+    0
+}
+
+fn list() {
+    let dirs = installed_dirs();
+    if dirs.is_empty() {
+        println!("{}", "ℹ️  Belum ada pack terpasang.".yellow());
+        return;
+    }
+    println!("  {:<24}{:<12}{:<24}{:>6}", "Nama".bold(), "Versi".bold(), "Author".bold(), "Quest".bold());
+    for dir in dirs {
+        match load(&dir) {
+            Ok(pack) => println!(
+                "  {:<24}{:<12}{:<24}{:>6}",
+                pack.manifest.name,
+                pack.manifest.version,
+                pack.manifest.author,
+                pack.quests.len()
+            ),
+            Err(errors) => println!(
+                "  {:<24}{} {}",
+                dir_name(&dir),
+                "❌ rusak:".red(),
+                errors.first().map(|e| e.to_string()).unwrap_or_default()
+            ),
+        }
+    }
+    println!("\n  Folder pack: {}", packs_dir().display());
+}
+
+fn remove(name: &str) -> i32 {
+    let dir = packs_dir().join(name);
+    if !is_valid_name(name) || !dir.is_dir() {
+        println!("{} `{}` tidak terpasang.", "❌ Pack".red(), name);
+        return 1;
+    }
+    if let Err(e) = fs::remove_dir_all(&dir) {
+        println!("{} {}", "❌ Gagal menghapus pack:".red(), e);
+        return 1;
+    }
+    println!("{} {} dihapus. Progress-nya tetap tersimpan.", "🗑️  Pack".green(), name.bold());
+    0
+}
+
+fn check_manifest(manifest: &Manifest, file: &str, text: &str) -> Vec<LoadError> {
+    let mut errors = Vec::new();
+    if !is_valid_name(&manifest.name) {
+        errors.push(LoadError::at_key(
+            file,
+            text,
+            "name",
+            "nama pack hanya boleh huruf kecil, angka, `-` dan `_`".to_string(),
+        ));
+    }
+    if RustVersion::parse(&manifest.version).is_none() {
+        errors.push(LoadError::at_key(
+            file,
+            text,
+            "version",
+            format!("versi `{}` tidak valid, tulis seperti `1.0.0`", manifest.version),
+        ));
+    }
+    match RustVersion::parse(&manifest.min_rustquest) {
+        None => errors.push(LoadError::at_key(
+            file,
+            text,
+            "min_rustquest",
+            format!("versi `{}` tidak valid, tulis seperti `0.1.0`", manifest.min_rustquest),
+        )),
+        Some(required) if Some(required) > RustVersion::parse(env!("CARGO_PKG_VERSION")) => {
+            errors.push(LoadError::at_key(
+                file,
+                text,
+                "min_rustquest",
+                format!(
+                    "pack ini butuh RustQuest {} atau lebih baru (terpasang {})",
+                    required,
+                    env!("CARGO_PKG_VERSION")
+                ),
+            ))
+        }
+        Some(_) => {}
+    }
+    if manifest.quests.is_empty() {
+        errors.push(LoadError::at_key(
+            file,
+            text,
+            "quests",
+            "pack harus berisi minimal satu file quest".to_string(),
+        ));
+    }
+    errors
+}
+
+fn is_valid_name(name: &str) -> bool {
+    Regex::new(r"^[a-z0-9][a-z0-9_-]*$").unwrap().is_match(name)
+}
+
+/// Path relatif yang tidak keluar dari folder induknya (tanpa `..` atau
+/// `/` di depan), misal file quest di pack atau file project Cargo.
+pub fn is_inside(relative: &str) -> bool {
+    Path::new(relative)
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// Apakah `path`, setelah symlink diikuti, berada di luar folder pack
+/// `dir`. Path yang tidak ada dilaporkan nanti saat file dibaca.
+fn escapes(dir: &Path, path: &Path) -> bool {
+    match (dir.canonicalize(), path.canonicalize()) {
+        (Ok(dir), Ok(path)) => !path.starts_with(dir),
+        _ => false,
+    }
+}
+
+/// Ekstrak arsip `.tar`, `.tar.gz`, atau `.tgz` dengan `tar` sistem.
+fn extract(archive: &Path) -> io::Result<tempfile::TempDir> {
+    let dir = tempfile::Builder::new().prefix("rustquest-pack-").tempdir()?;
+    let output = Command::new("tar")
+        .arg("-xf")
+        .arg(archive)
+        .arg("-C")
+        .arg(dir.path())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(dir)
+}
+
+/// Folder berisi manifest: root arsip, atau satu-satunya folder di
+/// dalamnya (arsip yang dibuat dengan `tar -czf pack.tar.gz nama-pack/`).
+fn pack_root(dir: &Path) -> Option<PathBuf> {
+    if dir.join(MANIFEST_FILE).is_file() {
+        return Some(dir.to_path_buf());
+    }
+    let entries: Vec<PathBuf> = fs::read_dir(dir).ok()?.filter_map(Result::ok).map(|e| e.path()).collect();
+    match entries.as_slice() {
+        [only] if only.join(MANIFEST_FILE).is_file() => Some(only.clone()),
+        _ => None,
+    }
+}
+
+/// Salin manifest dan file quest yang tercantum ke `target`, menggantikan
+/// versi lama jika ada.
+fn copy_pack(root: &Path, manifest: &Manifest, target: &Path) -> io::Result<()> {
+    if target.exists() {
+        fs::remove_dir_all(target)?;
+    }
+    let files = std::iter::once(MANIFEST_FILE).chain(manifest.quests.iter().map(String::as_str));
+    for relative in files {
+        let source = root.join(relative);
+        if escapes(root, &source) {
+            return Err(io::Error::other(format!("`{}` adalah symlink ke luar folder pack", relative)));
+        }
+        let destination = target.join(relative);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(source, destination)?;
+    }
+    Ok(())
+}
+
+fn dir_name(dir: &Path) -> String {
+    dir.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn packs_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(PACKS_ENV) {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = env::var_os("XDG_DATA_HOME") {
+        return PathBuf::from(dir).join("rustquest").join("packs");
+    }
+    if let Some(home) = env::var_os("HOME") {
+        return PathBuf::from(home).join(".local").join("share").join("rustquest").join("packs");
+    }
+    env::temp_dir().join("rustquest-packs")
+}
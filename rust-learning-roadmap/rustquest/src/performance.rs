@@ -0,0 +1,113 @@
+// Performance Budgets - Measure Optimized Builds
+// ==============================================
+
+use crate::exercises::{PerformanceBudget, TestCase};
+use crate::sandbox::{self, Limits, Termination};
+use colored::Colorize;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// Argumen `rustc` tambahan untuk exercise performa (setara mode release).
+pub const OPTIMIZE_ARGS: [&str; 2] = ["-C", "opt-level=3"];
+
+/// Hasil pengukuran beberapa kali run.
+pub struct Measurement {
+    pub median: Duration,
+    pub peak_memory_kb: u64,
+}
+
+/// Jalankan `binary` sebanyak `runs` kali dengan input `case`. Gagal jika
+/// salah satu run tidak keluar dengan sukses.
+pub fn measure(
+    binary: &Path,
+    dir: &Path,
+    case: &TestCase,
+    runs: usize,
+    limits: &Limits,
+) -> Result<Measurement, String> {
+    let mut times = Vec::new();
+    let mut peak_memory_kb = 0;
+    for _ in 0..runs.max(1) {
+        let mut command = Command::new(binary);
+        command
+            .args(&case.args)
+            .env("RUST_BACKTRACE", "0")
+            .current_dir(dir);
+        let execution = sandbox::run(command, &case.stdin, limits).map_err(|e| e.to_string())?;
+        if execution.termination != Termination::Exited(0) {
+            return Err(format!("program berhenti dengan {:?}", execution.termination));
+        }
+        times.push(execution.elapsed);
+        peak_memory_kb = peak_memory_kb.max(execution.peak_memory_kb);
+    }
+    times.sort();
+    Ok(Measurement {
+        median: times[times.len() / 2],
+        peak_memory_kb,
+    })
+}
+
+/// Tampilkan angka learner di samping budget dan solusi referensi.
+/// Mengembalikan `true` jika waktu dan memori masih dalam budget.
+pub fn print_report(budget: &PerformanceBudget, mine: &Measurement, reference: Option<&Measurement>) -> bool {
+    let time_ok = mine.median <= budget.max_time();
+    let memory_ok = mine.peak_memory_kb <= budget.max_memory_kb;
+
+    println!(
+        "\n{}",
+        format!("⏱️  Performa (median {} run, build optimized):", budget.runs).cyan().bold()
+    );
+    println!("  {:<10}{:>12}{:>12}{:>20}", "", "Kamu", "Budget", "Solusi referensi");
+    println!(
+        "  {:<10}{:>12}{:>12}{:>20}  {}",
+        "Waktu",
+        format_time(mine.median),
+        format_time(budget.max_time()),
+        reference.map_or("-".to_string(), |r| format_time(r.median)),
+        status(time_ok)
+    );
+    println!(
+        "  {:<10}{:>12}{:>12}{:>20}  {}",
+        "Memori",
+        format_memory(mine.peak_memory_kb),
+        format_memory(budget.max_memory_kb),
+        reference.map_or("-".to_string(), |r| format_memory(r.peak_memory_kb)),
+        status(memory_ok)
+    );
+
+    time_ok && memory_ok
+}
+
+/// Ringkasan satu baris untuk riwayat submit, misal
+/// "Melebihi budget: waktu 312.0 ms > 200.0 ms".
+pub fn summary(budget: &PerformanceBudget, mine: &Measurement) -> String {
+    let mut over = Vec::new();
+    if mine.median > budget.max_time() {
+        over.push(format!("waktu {} > {}", format_time(mine.median), format_time(budget.max_time())));
+    }
+    if mine.peak_memory_kb > budget.max_memory_kb {
+        over.push(format!(
+            "memori {} > {}",
+            format_memory(mine.peak_memory_kb),
+            format_memory(budget.max_memory_kb)
+        ));
+    }
+    format!("Melebihi budget: {}", over.join(", "))
+}
+
+fn status(ok: bool) -> String {
+    if ok {
+        "✅".to_string()
+    } else {
+        "❌".red().to_string()
+    }
+}
+
+fn format_time(duration: Duration) -> String {
+    format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn format_memory(kb: u64) -> String {
+    format!("{:.1} MB", kb as f64 / 1024.0)
+}
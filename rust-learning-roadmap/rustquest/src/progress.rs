@@ -1,9 +1,11 @@
 // Progress Tracking System
 // ========================
 
+use crate::exercises::Quest;
+use crate::history::{Attempt, AttemptVerdict};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
@@ -12,6 +14,16 @@ const PROGRESS_FILE: &str = "progress.json";
 #[derive(Serialize, Deserialize, Default)]
 pub struct Progress {
     pub completed: HashSet<String>,
+    /// Semua percobaan submit per exercise id, dari yang paling lama.
+    #[serde(default)]
+    pub history: HashMap<String, Vec<Attempt>>,
+    /// Exercise yang diselesaikan dengan membuka solusi referensi. Tetap
+    /// dihitung selesai, tapi tidak dianggap dikuasai untuk badge.
+    #[serde(default)]
+    pub solutions_viewed: HashSet<String>,
+    /// Jumlah hint yang sudah dibuka per exercise id.
+    #[serde(default)]
+    pub hints_used: HashMap<String, usize>,
 }
 
 impl Progress {
@@ -34,38 +46,94 @@ impl Progress {
         self.save();
     }
     
+    pub fn record_attempt(&mut self, id: &str, attempt: Attempt) {
+        self.history.entry(id.to_string()).or_default().push(attempt);
+        self.save();
+    }
+    
+    /// Catat bahwa learner membuka solusi: exercise selesai dengan solusi.
+    pub fn mark_solution_viewed(&mut self, id: &str) {
+        self.completed.insert(id.to_string());
+        self.solutions_viewed.insert(id.to_string());
+        self.save();
+    }
+    
+    /// Buka hint berikutnya (maksimal `total`). Mengembalikan jumlah hint
+    /// yang sudah terbuka.
+    pub fn reveal_hint(&mut self, id: &str, total: usize) -> usize {
+        let used = self.hints_used.entry(id.to_string()).or_insert(0);
+        *used = (*used + 1).min(total);
+        let used = *used;
+        self.save();
+        used
+    }
+    
+    pub fn hints_used(&self, id: &str) -> usize {
+        self.hints_used.get(id).copied().unwrap_or(0)
+    }
+    
     pub fn is_completed(&self, id: &str) -> bool {
         self.completed.contains(id)
     }
     
+    /// Selesai tanpa membuka solusi.
+    pub fn is_mastered(&self, id: &str) -> bool {
+        self.is_completed(id) && !self.solutions_viewed.contains(id)
+    }
+    
+    pub fn used_solution(&self, id: &str) -> bool {
+        self.solutions_viewed.contains(id)
+    }
+    
+    /// Jumlah submit yang tidak lulus untuk exercise ini.
+    pub fn failed_attempts(&self, id: &str) -> usize {
+        self.history
+            .get(id)
+            .map(|attempts| {
+                attempts
+                    .iter()
+                    .filter(|a| !matches!(a.verdict, AttemptVerdict::Passed))
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+    
     pub fn reset(&mut self) {
         self.completed.clear();
+        self.history.clear();
+        self.solutions_viewed.clear();
+        self.hints_used.clear();
         self.save();
     }
     
-    pub fn show_progress(&self) {
-        use crate::exercises::get_all_quests;
-        
+    pub fn show_progress(&self, quests: &[Quest]) {
         println!("\n{}", "═══════════════════════════════════════".magenta());
         println!("{}", "📊 PROGRESS KAMU".magenta().bold());
         println!("{}", "═══════════════════════════════════════".magenta());
         
-        let quests = get_all_quests();
         let mut total_exercises = 0;
         let mut total_completed = 0;
+        let mut total_mastered = 0;
         
-        for quest in &quests {
+        for quest in quests {
             let completed_count = quest.exercises
                 .iter()
                 .filter(|e| self.is_completed(&e.id))
                 .count();
+            let mastered_count = quest.exercises
+                .iter()
+                .filter(|e| self.is_mastered(&e.id))
+                .count();
             let total = quest.exercises.len();
             
             total_exercises += total;
             total_completed += completed_count;
+            total_mastered += mastered_count;
             
-            let status = if completed_count == total {
+            let status = if mastered_count == total {
                 "✅ SELESAI".green().to_string()
+            } else if completed_count == total {
+                format!("📖 SELESAI ({} dengan solusi)", total - mastered_count).cyan().to_string()
             } else if completed_count > 0 {
                 format!("🔄 {}/{}", completed_count, total).yellow().to_string()
             } else {
@@ -90,21 +158,36 @@ impl Progress {
             total_exercises,
             percentage
         );
+        if total_completed > total_mastered {
+            println!(
+                "  {} {}  {} {}",
+                "🧠 Dikuasai:".green(),
+                total_mastered,
+                "📖 Dengan solusi:".cyan(),
+                total_completed - total_mastered
+            );
+        }
+        
+        let hints: usize = self.hints_used.values().sum();
+        if hints > 0 {
+            println!("  {} {}", "💡 Hint dipakai:".yellow(), hints);
+        }
         
         // Achievement badges
         println!("\n{}", "🏆 BADGES:".yellow().bold());
         
-        if total_completed >= 5 {
-            println!("  {} Rust Seedling - Selesaikan 5 quest pertama", "🌱".to_string());
+        // Badge hanya dihitung dari exercise yang dikuasai tanpa solusi
+        if total_mastered >= 5 {
+            println!("  🌱 Rust Seedling - Selesaikan 5 quest pertama");
         }
-        if total_completed >= 10 {
-            println!("  {} Flow Master - Selesaikan 10 quest", "🔧".to_string());
+        if total_mastered >= 10 {
+            println!("  🔧 Flow Master - Selesaikan 10 quest");
         }
-        if self.completed.iter().any(|id| id.starts_with("1.4")) {
-            println!("  {} Ownership King - Menguasai Ownership", "👑".to_string());
+        if self.completed.iter().any(|id| id.starts_with("1.4") && self.is_mastered(id)) {
+            println!("  👑 Ownership King - Menguasai Ownership");
         }
-        if total_completed == total_exercises {
-            println!("  {} Fundamentals Complete! - Semua quest selesai!", "🎖️".to_string());
+        if total_mastered == total_exercises {
+            println!("  🎖️ Fundamentals Complete! - Semua quest selesai!");
         }
         
         println!();
@@ -0,0 +1,110 @@
+// Quizzes - Questions Graded Without a Compiler
+// =============================================
+
+use crate::matcher::OutputMatcher;
+use colored::Colorize;
+use serde::Deserialize;
+
+/// Pertanyaan yang dinilai langsung oleh runner tanpa compile. Di file
+/// quest jenisnya ditulis di field `quiz`, misal `quiz = "short_answer"`.
+#[derive(Deserialize)]
+#[serde(tag = "quiz", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Quiz {
+    /// Tepat satu pilihan benar (`answer` adalah index pilihan).
+    MultipleChoice { options: Vec<String>, answer: usize },
+    /// Semua pilihan benar harus dipilih, tidak kurang dan tidak lebih.
+    MultiSelect { options: Vec<String>, answers: Vec<usize> },
+    /// Jawaban teks pendek. Cocok jika sama dengan salah satu `accepted`,
+    /// tanpa membedakan huruf besar/kecil dan spasi di awal/akhir.
+    ShortAnswer { accepted: Vec<String> },
+    /// Tebak output `code_template` tanpa menjalankannya.
+    PredictOutput { expected: OutputMatcher },
+}
+
+impl Quiz {
+    pub fn options(&self) -> &[String] {
+        match self {
+            Quiz::MultipleChoice { options, .. } | Quiz::MultiSelect { options, .. } => options,
+            Quiz::ShortAnswer { .. } | Quiz::PredictOutput { .. } => &[],
+        }
+    }
+
+    /// Nilai jawaban learner seperti yang dikembalikan `normalize_choices`
+    /// (untuk pilihan) atau teks apa adanya.
+    pub fn is_correct(&self, answer: &str) -> bool {
+        match self {
+            Quiz::MultipleChoice { answer: correct, .. } => {
+                parse_choices(answer, self.options().len()).as_deref() == Some(&[*correct][..])
+            }
+            Quiz::MultiSelect { answers, .. } => {
+                let mut correct = answers.clone();
+                correct.sort_unstable();
+                correct.dedup();
+                parse_choices(answer, self.options().len()) == Some(correct)
+            }
+            Quiz::ShortAnswer { accepted } => accepted
+                .iter()
+                .any(|a| a.trim().eq_ignore_ascii_case(answer.trim())),
+            Quiz::PredictOutput { expected } => expected.matches(answer),
+        }
+    }
+
+    /// Penjelasan singkat kenapa jawaban salah, tanpa membocorkan jawaban.
+    pub fn feedback(&self, answer: &str) -> String {
+        match self {
+            Quiz::MultipleChoice { .. } => format!("Pilihan {} belum tepat.", answer),
+            Quiz::MultiSelect { answers, .. } => {
+                let chosen = parse_choices(answer, self.options().len()).unwrap_or_default();
+                let right = chosen.iter().filter(|i| answers.contains(i)).count();
+                format!(
+                    "{} dari {} pilihan kamu benar; jawaban lengkapnya ada {} pilihan.",
+                    right,
+                    chosen.len(),
+                    answers.len()
+                )
+            }
+            Quiz::ShortAnswer { .. } => format!("\"{}\" belum tepat.", answer.trim()),
+            Quiz::PredictOutput { .. } => "Output tebakanmu belum sama dengan output program.".to_string(),
+        }
+    }
+}
+
+/// Huruf pilihan: 0 -> A, 1 -> B, dan seterusnya.
+pub fn letter(index: usize) -> char {
+    (b'A' + index as u8) as char
+}
+
+pub fn print_options(options: &[String]) {
+    for (i, option) in options.iter().enumerate() {
+        println!("  {} {}", format!("{}.", letter(i)).cyan().bold(), option);
+    }
+}
+
+/// Baca pilihan seperti `b`, `A, c`, atau `a c` menjadi index yang urut dan
+/// unik. `None` jika ada huruf di luar jumlah pilihan.
+pub fn parse_choices(input: &str, count: usize) -> Option<Vec<usize>> {
+    let mut chosen = Vec::new();
+    for part in input.split([',', ' ']).filter(|p| !p.is_empty()) {
+        let mut chars = part.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return None;
+        };
+        let index = (c.to_ascii_uppercase() as usize).checked_sub('A' as usize)?;
+        if index >= count {
+            return None;
+        }
+        chosen.push(index);
+    }
+    chosen.sort_unstable();
+    chosen.dedup();
+    Some(chosen)
+}
+
+/// Tulis pilihan sebagai huruf, misal `A, C`, untuk riwayat submit.
+pub fn normalize_choices(choices: &[usize]) -> String {
+    choices
+        .iter()
+        .map(|&i| letter(i).to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
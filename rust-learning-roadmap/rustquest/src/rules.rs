@@ -0,0 +1,212 @@
+// Code Rules - Structural Checks on the Learner's Source
+// ======================================================
+
+use quote::ToTokens;
+use serde::Deserialize;
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Expr, FnArg, Token};
+
+/// Aturan struktur kode yang dicek dari hasil parsing source learner,
+/// sebelum program dijalankan.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeRule {
+    /// Harus memanggil method ini, misal `clone`.
+    CallsMethod(String),
+    /// Harus mendefinisikan fungsi dengan nama dan tipe parameter ini,
+    /// misal `hitung_panjang` dengan `["&String"]`.
+    DefinesFn { name: String, params: Vec<String> },
+    /// Tidak boleh memakai `unsafe` (blok, fungsi, maupun impl).
+    NoUnsafe,
+    /// Harus memakai ekspresi `match`.
+    UsesMatch,
+    /// Tidak boleh ada string literal yang mengandung teks ini, misal output
+    /// yang seharusnya dihasilkan oleh kode.
+    ForbidsLiteral(String),
+}
+
+/// Cek semua `rules` terhadap `code`. Mengembalikan pesan untuk setiap
+/// aturan yang dilanggar. Kode yang gagal di-parse tidak dicek di sini;
+/// error-nya akan dilaporkan oleh compiler.
+pub fn check(code: &str, rules: &[CodeRule]) -> Vec<String> {
+    if rules.is_empty() {
+        return Vec::new();
+    }
+    let Ok(file) = syn::parse_file(code) else {
+        return Vec::new();
+    };
+
+    let mut facts = Facts::default();
+    facts.visit_file(&file);
+
+    rules
+        .iter()
+        .filter(|rule| !facts.satisfies(rule))
+        .map(message)
+        .collect()
+}
+
+fn message(rule: &CodeRule) -> String {
+    match rule {
+        CodeRule::CallsMethod(method) => {
+            format!("Exercise ini harus memakai method `.{}()`.", method)
+        }
+        CodeRule::DefinesFn { name, params } => format!(
+            "Definisikan fungsi `{}({})` sesuai soal.",
+            name,
+            params.join(", ")
+        ),
+        CodeRule::NoUnsafe => "Exercise ini tidak boleh memakai `unsafe`.".to_string(),
+        CodeRule::UsesMatch => "Gunakan ekspresi `match` untuk menyelesaikan exercise ini.".to_string(),
+        CodeRule::ForbidsLiteral(text) => format!(
+            "Jangan menulis \"{}\" langsung sebagai teks; hasilkan lewat kode.",
+            text
+        ),
+    }
+}
+
+/// Cek bahwa setiap fungsi (termasuk method di `impl`) yang ada di
+/// `template` masih ada di `code` dengan signature yang sama persis.
+/// Mengembalikan pesan untuk setiap signature yang berubah atau hilang.
+/// Template yang gagal di-parse tidak dicek.
+pub fn changed_signatures(template: &str, code: &str) -> Vec<String> {
+    let Ok(file) = syn::parse_file(template) else {
+        return Vec::new();
+    };
+    let mut expected = Signatures::default();
+    expected.visit_file(&file);
+
+    let mut actual = Signatures::default();
+    if let Ok(file) = syn::parse_file(code) {
+        actual.visit_file(&file);
+    }
+
+    expected
+        .0
+        .iter()
+        .filter(|(_, signature)| !actual.0.iter().any(|(_, s)| s == signature))
+        .map(|(name, signature)| {
+            format!(
+                "Jangan ubah signature fungsi `{}`.",
+                source_line(template, name).unwrap_or(signature)
+            )
+        })
+        .collect()
+}
+
+/// Baris di `source` tempat fungsi `name` didefinisikan, tanpa `{` di
+/// akhir, agar signature tampil seperti yang ditulis di template.
+fn source_line<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let needle = format!("fn {}", name);
+    source
+        .lines()
+        .map(str::trim)
+        .find(|line| {
+            line.split_once(&needle)
+                .is_some_and(|(_, rest)| rest.starts_with(['(', '<']))
+        })
+        .map(|line| line.trim_end_matches('{').trim_end())
+}
+
+/// Nama dan signature (token yang sudah dinormalisasi) semua fungsi di
+/// sebuah file.
+#[derive(Default)]
+struct Signatures(Vec<(String, String)>);
+
+impl<'ast> Visit<'ast> for Signatures {
+    fn visit_signature(&mut self, node: &'ast syn::Signature) {
+        let tokens = normalize_tokens(&node.to_token_stream().to_string());
+        self.0.push((node.ident.to_string(), tokens));
+    }
+}
+
+/// Fakta tentang kode learner yang dikumpulkan dari syntax tree.
+#[derive(Default)]
+struct Facts {
+    methods: Vec<String>,
+    functions: Vec<(String, Vec<String>)>,
+    uses_unsafe: bool,
+    uses_match: bool,
+    literals: Vec<String>,
+}
+
+impl Facts {
+    fn satisfies(&self, rule: &CodeRule) -> bool {
+        match rule {
+            CodeRule::CallsMethod(method) => self.methods.iter().any(|m| m == method),
+            CodeRule::DefinesFn { name, params } => {
+                let expected: Vec<String> = params.iter().map(|p| normalize_type(p)).collect();
+                self.functions.iter().any(|(n, p)| n == name && *p == expected)
+            }
+            CodeRule::NoUnsafe => !self.uses_unsafe,
+            CodeRule::UsesMatch => self.uses_match,
+            CodeRule::ForbidsLiteral(text) => !self.literals.iter().any(|l| l.contains(text.as_str())),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Facts {
+    fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
+        self.methods.push(node.method.to_string());
+        visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_match(&mut self, node: &'ast syn::ExprMatch) {
+        self.uses_match = true;
+        visit::visit_expr_match(self, node);
+    }
+
+    fn visit_expr_unsafe(&mut self, node: &'ast syn::ExprUnsafe) {
+        self.uses_unsafe = true;
+        visit::visit_expr_unsafe(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        self.uses_unsafe |= node.unsafety.is_some();
+        visit::visit_item_impl(self, node);
+    }
+
+    fn visit_signature(&mut self, node: &'ast syn::Signature) {
+        self.uses_unsafe |= node.unsafety.is_some();
+        let params = node
+            .inputs
+            .iter()
+            .map(|arg| match arg {
+                FnArg::Typed(typed) => normalize_tokens(&typed.ty.to_token_stream().to_string()),
+                FnArg::Receiver(receiver) => normalize_tokens(&receiver.to_token_stream().to_string()),
+            })
+            .collect();
+        self.functions.push((node.ident.to_string(), params));
+        visit::visit_signature(self, node);
+    }
+
+    fn visit_lit_str(&mut self, node: &'ast syn::LitStr) {
+        self.literals.push(node.value());
+    }
+
+    /// Argumen macro seperti `println!` tidak di-parse oleh syn, jadi parse
+    /// sendiri sebagai daftar ekspresi agar literal dan method call di
+    /// dalamnya ikut dicek.
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        if let Ok(args) = node.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+        visit::visit_macro(self, node);
+    }
+}
+
+/// Samakan penulisan tipe dari aturan dengan hasil parsing, misal
+/// `&mut String` dan `& mut String`.
+fn normalize_type(ty: &str) -> String {
+    match syn::parse_str::<syn::Type>(ty) {
+        Ok(ty) => normalize_tokens(&ty.to_token_stream().to_string()),
+        Err(_) => normalize_tokens(ty),
+    }
+}
+
+fn normalize_tokens(tokens: &str) -> String {
+    tokens.split_whitespace().collect::<Vec<_>>().join(" ")
+}
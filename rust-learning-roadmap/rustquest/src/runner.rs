@@ -1,16 +1,59 @@
 // Quest Runner - Execute and Validate Code
 // =========================================
 
-use crate::exercises::{Exercise, Quest};
+use crate::blanks::{self, BLANK};
+use crate::cache;
+use crate::cargo_project::{self, CargoProject};
+use crate::diagnostics::{self, Diagnostic};
+use crate::drafts;
+use crate::diff;
+use crate::exercises::{
+    Exercise, ExerciseKind, ExpectedError, ExpectedExit, PerformanceBudget, Quest, TestCase,
+};
+use crate::hidden_tests;
+use crate::history::{Attempt, AttemptVerdict};
+use crate::matcher::OutputMatcher;
+use crate::performance::{self, Measurement};
+use crate::rules;
 use crate::progress::Progress;
+use crate::quiz::{self, Quiz};
+use crate::sandbox::{self, Execution, Limits, Termination};
+use crate::toolchain;
+use crate::workspace::Workspace;
 use colored::Colorize;
+use regex::Regex;
 use std::fs;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+/// Hasil akhir satu kali submit kode.
+pub enum Verdict {
+    Passed,
+    CompileError(Vec<Diagnostic>),
+    /// Program panic atau dihentikan sinyal saat tidak diharapkan.
+    Crashed,
+    Failed,
+}
+
+/// Jumlah submit gagal sebelum opsi "Lihat solusi" terbuka tanpa harus
+/// menyerah.
+const SOLUTION_UNLOCK_FAILURES: usize = 3;
+
+/// Catatan pengecekan untuk riwayat submit: alasan gagal dan output
+/// program yang ditampilkan ke learner.
+#[derive(Default)]
+struct Report {
+    summary: String,
+    output: String,
+    /// Baris kode yang kemungkinan membuat case gagal: lokasi panic, atau
+    /// `println!` yang mencetak baris output pertama yang berbeda.
+    failure_line: Option<usize>,
+}
+
 pub fn run_quest(quest: &Quest, progress: &mut Progress) {
     println!("\n{}", "═══════════════════════════════════════".yellow());
-    println!("{} {}", "⚔️".to_string(), quest.name.yellow().bold());
+    println!("⚔️ {}", quest.name.yellow().bold());
     println!("{}", "═══════════════════════════════════════".yellow());
     println!("{}", quest.description);
     println!("{} {}", "💡 Hint:".cyan(), quest.hint);
@@ -18,7 +61,11 @@ pub fn run_quest(quest: &Quest, progress: &mut Progress) {
     for (i, exercise) in quest.exercises.iter().enumerate() {
         println!("\n{}", "───────────────────────────────────────".white());
         
-        let status = if progress.is_completed(&exercise.id) {
+        let status = if exercise.unavailable().is_some() {
+            "🚫".to_string()
+        } else if progress.used_solution(&exercise.id) {
+            "📖".to_string()
+        } else if progress.is_completed(&exercise.id) {
             "✅".to_string()
         } else {
             "⬜".to_string()
@@ -26,7 +73,9 @@ pub fn run_quest(quest: &Quest, progress: &mut Progress) {
         
         println!("{} Exercise {}/{}: {}", status, i + 1, quest.exercises.len(), exercise.id);
         
-        if !progress.is_completed(&exercise.id) {
+        if let Some(reason) = exercise.unavailable() {
+            println!("{} {}", "🚫 Exercise tidak tersedia:".yellow(), reason);
+        } else if !progress.is_completed(&exercise.id) {
             run_exercise(exercise, progress);
         }
     }
@@ -36,41 +85,288 @@ pub fn run_quest(quest: &Quest, progress: &mut Progress) {
     io::stdin().read_line(&mut input).unwrap();
 }
 
+/// Pilihan di menu exercise. Exercise pertanyaan dijawab dengan teks, jadi
+/// tidak punya pilihan menulis kode.
+#[derive(Clone, Copy)]
+enum Action {
+    Write,
+    FillBlanks,
+    Editor,
+    Answer,
+    Hint,
+    Skip,
+    Solution,
+}
+
+fn menu_actions(exercise: &Exercise) -> Vec<Action> {
+    let mut actions = if exercise.is_question() {
+        vec![Action::Answer]
+    } else if blanks::find_blanks(&exercise.code_template).is_empty() {
+        vec![Action::Write, Action::Editor]
+    } else {
+        vec![Action::Write, Action::FillBlanks, Action::Editor]
+    };
+    actions.extend([Action::Hint, Action::Skip]);
+    if exercise.solution.is_some() {
+        actions.push(Action::Solution);
+    }
+    actions
+}
+
+fn action_label(action: Action, exercise: &Exercise, progress: &Progress) -> String {
+    match action {
+        Action::Write => "✏️  Tulis jawaban lengkap".to_string(),
+        Action::FillBlanks => format!("🧩 Isi bagian kosong ({})", BLANK),
+        Action::Editor => "📝 Edit di editor ($VISUAL/$EDITOR)".to_string(),
+        Action::Answer => "✏️  Jawab".to_string(),
+        Action::Hint => format!(
+            "💡 Lihat hint ({}/{} terbuka)",
+            progress.hints_used(&exercise.id),
+            exercise.hints.len()
+        ),
+        Action::Skip => "⏭️  Skip exercise ini".to_string(),
+        Action::Solution => {
+            let failures = progress.failed_attempts(&exercise.id);
+            if failures >= SOLUTION_UNLOCK_FAILURES {
+                "📖 Lihat solusi".to_string()
+            } else {
+                format!(
+                    "🔒 Lihat solusi (terbuka setelah {} percobaan gagal, sekarang {})",
+                    SOLUTION_UNLOCK_FAILURES, failures
+                )
+            }
+        }
+    }
+}
+
 fn run_exercise(exercise: &Exercise, progress: &mut Progress) {
-    println!("{}", exercise.question.cyan());
-    println!("\n{}", "📝 Code Template:".yellow().bold());
-    println!("{}", "```rust".white());
-    println!("{}", exercise.code_template.white());
-    println!("{}", "```".white());
+    println!("\n{}", exercise.question.cyan());
+    if !exercise.code_template.is_empty() {
+        let title = match exercise.kind {
+            ExerciseKind::FixBug {} => "🐛 Kode bermasalah:",
+            _ if exercise.is_question() => "📝 Kode:",
+            _ => "📝 Code Template:",
+        };
+        println!("\n{}", title.yellow().bold());
+        println!("{}", "```rust".white());
+        println!("{}", exercise.code_template.white());
+        println!("{}", "```".white());
+    }
     
+    let actions = menu_actions(exercise);
     loop {
         println!("\n{}", "Pilihan:".yellow());
-        println!("  1. ✏️  Tulis jawaban");
-        println!("  2. 💡 Lihat hint");
-        println!("  3. ⏭️  Skip exercise ini");
+        for (i, action) in actions.iter().enumerate() {
+            println!("  {}. {}", i + 1, action_label(*action, exercise, progress));
+        }
         
-        print!("{}", "Pilih (1-3): ".yellow());
+        print!("{}", format!("Pilih (1-{}): ", actions.len()).yellow());
         io::stdout().flush().unwrap();
         
         let mut input = String::new();
         io::stdin().read_line(&mut input).unwrap();
         
-        match input.trim() {
-            "1" => {
-                if write_and_test_code(exercise, progress) {
-                    break;
-                }
-            }
-            "2" => {
-                println!("\n{} {}", "💡 Hint:".cyan().bold(), exercise.hint);
+        let Some(action) = input
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| actions.get(i))
+        else {
+            println!("{}", "❌ Pilihan tidak valid!".red());
+            continue;
+        };
+        
+        let done = match action {
+            Action::Write => write_and_test_code(exercise, progress),
+            Action::FillBlanks => fill_blanks(exercise, progress),
+            Action::Editor => edit_in_editor(exercise, progress),
+            Action::Answer => answer_question(exercise, progress),
+            Action::Hint => {
+                show_next_hint(exercise, progress);
+                false
             }
-            "3" => {
+            Action::Skip => {
                 println!("{}", "⏭️  Exercise di-skip.".yellow());
-                break;
+                true
             }
-            _ => println!("{}", "❌ Pilihan tidak valid!".red()),
+            Action::Solution => show_solution(exercise, progress),
+        };
+        if done {
+            break;
+        }
+    }
+}
+
+/// Buka hint tingkat berikutnya. Jika semua sudah terbuka, tampilkan
+/// ulang semuanya.
+fn show_next_hint(exercise: &Exercise, progress: &mut Progress) {
+    let total = exercise.hints.len();
+    if total == 0 {
+        println!("{}", "ℹ️  Exercise ini tidak punya hint.".yellow());
+        return;
+    }
+    
+    if progress.hints_used(&exercise.id) >= total {
+        println!("\n{}", "💡 Semua hint sudah terbuka:".cyan().bold());
+        for (i, hint) in exercise.hints.iter().enumerate() {
+            println!("  {} {}", format!("{}.", i + 1).cyan(), hint);
+        }
+        return;
+    }
+    
+    let used = progress.reveal_hint(&exercise.id, total);
+    println!(
+        "\n{} {}",
+        format!("💡 Hint {}/{}:", used, total).cyan().bold(),
+        exercise.hints[used - 1]
+    );
+}
+
+/// Tampilkan solusi referensi. Sebelum cukup banyak percobaan gagal,
+/// learner harus mengonfirmasi bahwa ia menyerah. Exercise lalu tercatat
+/// selesai dengan solusi.
+fn show_solution(exercise: &Exercise, progress: &mut Progress) -> bool {
+    let Some(solution) = &exercise.solution else {
+        return false;
+    };
+    
+    if progress.failed_attempts(&exercise.id) < SOLUTION_UNLOCK_FAILURES {
+        println!("\n{}", "🔒 Solusi belum terbuka.".yellow().bold());
+        println!("Kalau kamu menyerah sekarang, exercise ini tercatat \"selesai dengan solusi\",");
+        println!("bukan dikuasai, dan tidak dihitung untuk badge.");
+        print!("{}", "Yakin mau lihat solusi? (y/n): ".yellow());
+        io::stdout().flush().unwrap();
+        
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).unwrap();
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("{}", "💪 Semangat! Coba lihat hint dulu.".green());
+            return false;
         }
     }
+    
+    println!("\n{}", "═══════════════════════════════════════".cyan());
+    println!("{}", "📖 SOLUSI REFERENSI".cyan().bold());
+    println!("{}", "═══════════════════════════════════════".cyan());
+    if let ExerciseKind::Quiz(_) = exercise.kind {
+        println!("{} {}", "✅ Jawaban:".green().bold(), solution.code);
+    } else {
+        println!("{}", "```rust".white());
+        println!("{}", solution.code.white());
+        println!("{}", "```".white());
+    }
+    println!("\n{} {}", "💡 Penjelasan:".yellow().bold(), solution.explanation);
+    println!("\n{}", "📖 Exercise ini tercatat selesai dengan solusi.".cyan());
+    
+    progress.mark_solution_viewed(&exercise.id);
+    true
+}
+
+/// Minta jawaban teks untuk exercise pertanyaan, lalu nilai jawabannya.
+fn answer_question(exercise: &Exercise, progress: &mut Progress) -> bool {
+    let answer = match &exercise.kind {
+        ExerciseKind::PredictError { ask_line } => {
+            let code = prompt("Error code (misal E0308): ");
+            let code = normalize_error_code(&code);
+            if code.is_empty() {
+                println!("{}", "❌ Error code tidak boleh kosong!".red());
+                return false;
+            }
+            if *ask_line {
+                let line = prompt("Baris error utama: ");
+                if line.parse::<usize>().is_err() {
+                    println!("{}", "❌ Baris harus berupa angka!".red());
+                    return false;
+                }
+                format!("{} baris {}", code, line)
+            } else {
+                code
+            }
+        }
+        ExerciseKind::Quiz(quiz) => match ask_quiz(quiz) {
+            Some(answer) => answer,
+            None => return false,
+        },
+        _ => return false,
+    };
+    
+    if let Verdict::Passed = submit(exercise, &answer, progress) {
+        progress.mark_completed(&exercise.id);
+        true
+    } else {
+        false
+    }
+}
+
+/// Tampilkan pilihan (jika ada) dan baca jawaban kuis. Pilihan ditulis
+/// ulang sebagai huruf urut, misal `A, C`.
+fn ask_quiz(quiz: &Quiz) -> Option<String> {
+    let options = quiz.options();
+    if !options.is_empty() {
+        println!();
+        quiz::print_options(options);
+    }
+    let answer = match quiz {
+        Quiz::MultipleChoice { .. } => prompt("Jawaban (misal B): "),
+        Quiz::MultiSelect { .. } => prompt("Jawaban, pisahkan dengan koma (misal A, C): "),
+        Quiz::ShortAnswer { .. } => prompt("Jawaban: "),
+        Quiz::PredictOutput { .. } => {
+            println!("{}", "Tulis output program, ketik 'END' di baris baru untuk selesai:".white());
+            read_until_end()
+        }
+    };
+    if answer.trim().is_empty() {
+        println!("{}", "❌ Jawaban tidak boleh kosong!".red());
+        return None;
+    }
+    if options.is_empty() {
+        return Some(answer);
+    }
+    match quiz::parse_choices(&answer, options.len()) {
+        Some(choices) if !choices.is_empty() => Some(quiz::normalize_choices(&choices)),
+        _ => {
+            println!(
+                "{} {}",
+                "❌ Pilih huruf A sampai".red(),
+                quiz::letter(options.len() - 1).to_string().red()
+            );
+            None
+        }
+    }
+}
+
+/// Baca baris dari stdin sampai learner mengetik `END`.
+fn read_until_end() -> String {
+    let mut text = String::new();
+    loop {
+        let mut line = String::new();
+        io::stdin().read_line(&mut line).unwrap();
+        if line.trim() == "END" {
+            break;
+        }
+        text.push_str(&line);
+    }
+    text
+}
+
+fn prompt(label: &str) -> String {
+    print!("{}", label.yellow());
+    io::stdout().flush().unwrap();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    input.trim().to_string()
+}
+
+/// `e0382`, `0382`, dan `382` ditulis ulang menjadi `E0382`.
+fn normalize_error_code(input: &str) -> String {
+    let code = input.trim().to_uppercase();
+    let digits = code.strip_prefix('E').unwrap_or(&code);
+    if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+        format!("E{:0>4}", digits)
+    } else {
+        code
+    }
 }
 
 fn write_and_test_code(exercise: &Exercise, progress: &mut Progress) -> bool {
@@ -81,73 +377,1258 @@ fn write_and_test_code(exercise: &Exercise, progress: &mut Progress) -> bool {
     println!("{}", "Ketik 'END' di baris baru untuk selesai:".white());
     println!();
     
-    let mut code = String::new();
-    loop {
-        let mut line = String::new();
-        io::stdin().read_line(&mut line).unwrap();
-        if line.trim() == "END" {
-            break;
+    let code = read_until_end();
+    
+    if let Verdict::Passed = submit(exercise, &code, progress) {
+        progress.mark_completed(&exercise.id);
+        true
+    } else {
+        false
+    }
+}
+
+/// Buka draft exercise (awalnya berisi template) di editor learner, lalu
+/// compile dan validasi isinya setelah editor ditutup.
+fn edit_in_editor(exercise: &Exercise, progress: &mut Progress) -> bool {
+    let Some(editor) = drafts::editor() else {
+        println!("{}", "❌ Editor belum diatur. Set $EDITOR, misal: export EDITOR=nano".red());
+        return false;
+    };
+    
+    let path = match drafts::ensure_draft(exercise) {
+        Ok(path) => path,
+        Err(e) => {
+            println!("{} {}", "❌ Gagal menyiapkan draft:".red(), e);
+            return false;
+        }
+    };
+    
+    println!("{} {} {}", "📝 Membuka".cyan(), path.display(), format!("dengan {}...", editor).cyan());
+    match drafts::open_in_editor(&editor, &path) {
+        Ok(status) if status.success() => {}
+        Ok(status) => {
+            println!("{} {}", "❌ Editor keluar dengan status".red(), status);
+            return false;
+        }
+        Err(e) => {
+            println!("{} {}", "❌ Gagal membuka editor:".red(), e);
+            return false;
         }
-        code.push_str(&line);
     }
     
-    // Write code to temp file
-    let temp_file = "/tmp/rustquest_test.rs";
-    let temp_bin = "/tmp/rustquest_test";
+    let code = match fs::read_to_string(&path) {
+        Ok(code) => code,
+        Err(e) => {
+            println!("{} {}", "❌ Gagal membaca draft:".red(), e);
+            return false;
+        }
+    };
     
-    fs::write(temp_file, &code).expect("Gagal menulis file temp");
+    if let Verdict::Passed = submit(exercise, &code, progress) {
+        progress.mark_completed(&exercise.id);
+        true
+    } else {
+        println!("{} {}", "💾 Draft disimpan di".white(), path.display());
+        false
+    }
+}
+
+/// Minta jawaban untuk setiap `____` di template, sisipkan kembali ke
+/// template, lalu compile dan validasi hasilnya.
+fn fill_blanks(exercise: &Exercise, progress: &mut Progress) -> bool {
+    let template = &exercise.code_template;
+    let blanks = blanks::find_blanks(template);
+    if blanks.is_empty() {
+        println!("{}", "ℹ️  Exercise ini tidak punya bagian kosong. Tulis jawaban lengkap.".yellow());
+        return false;
+    }
+    
+    println!("\n{}", "═══════════════════════════════════════".green());
+    println!("{}", "🧩 ISI BAGIAN KOSONG".green().bold());
+    println!("{}", "═══════════════════════════════════════".green());
+    
+    let mut answers = Vec::with_capacity(blanks.len());
+    for i in 0..blanks.len() {
+        println!(
+            "\n{} {}",
+            format!("[#{}] baris {}:", i + 1, blanks[i].line).cyan(),
+            blanks::context_line(template, &blanks, i).white()
+        );
+        print!("{}", format!("Isi [#{}]: ", i + 1).yellow());
+        io::stdout().flush().unwrap();
+        
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer).unwrap();
+        answers.push(answer.trim().to_string());
+    }
+    
+    let empty: Vec<usize> = (0..answers.len()).filter(|&i| answers[i].is_empty()).collect();
+    if !empty.is_empty() {
+        println!("{} {}", "❌ Bagian ini masih kosong:".red(), blank_list(&empty));
+        return false;
+    }
+    
+    let code = blanks::fill(template, &blanks, &answers);
+    let (verdict, failure_line) = submit_checked(exercise, &code, progress);
+    match verdict {
+        Verdict::Passed => {
+            progress.mark_completed(&exercise.id);
+            true
+        }
+        Verdict::CompileError(errors) => {
+            let file = exercise.source_file();
+            let lines: Vec<usize> = errors.iter().filter_map(|e| e.primary_line(file)).collect();
+            let suspects = blanks::suspects(&blanks, &lines);
+            if !suspects.is_empty() {
+                println!("{} {}", "🔎 Kemungkinan salah di:".yellow().bold(), blank_list(&suspects));
+            }
+            false
+        }
+        Verdict::Failed | Verdict::Crashed => {
+            let suspects = failure_line.map(|line| blanks::nearest(&blanks, line)).unwrap_or_default();
+            if blanks.len() == 1 {
+                println!("{} {}", "🔎 Periksa lagi jawaban di:".yellow().bold(), blank_list(&[0]));
+            } else if let (Some(line), false) = (failure_line, suspects.is_empty()) {
+                println!(
+                    "{} {} (program salah mulai dari baris {})",
+                    "🔎 Kemungkinan salah di:".yellow().bold(),
+                    blank_list(&suspects),
+                    line
+                );
+            } else {
+                println!(
+                    "{}",
+                    "🔎 Kode berhasil compile, jadi cek kembali nilai/logika di setiap blank.".yellow()
+                );
+            }
+            false
+        }
+    }
+}
+
+fn blank_list(indices: &[usize]) -> String {
+    indices
+        .iter()
+        .map(|i| format!("[#{}]", i + 1))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Cek kode learner lalu simpan percobaan ini ke riwayat submit.
+pub fn submit(exercise: &Exercise, code: &str, progress: &mut Progress) -> Verdict {
+    submit_checked(exercise, code, progress).0
+}
+
+/// Seperti `submit`, ditambah baris kode yang kemungkinan membuat case
+/// gagal (lihat `Report::failure_line`).
+fn submit_checked(exercise: &Exercise, code: &str, progress: &mut Progress) -> (Verdict, Option<usize>) {
+    let mut report = Report::default();
+    let verdict = check_code(exercise, code, &mut report);
+    
+    let (attempt_verdict, diagnostics) = match &verdict {
+        Verdict::Passed => (AttemptVerdict::Passed, Vec::new()),
+        Verdict::CompileError(errors) => {
+            let file = exercise.source_file();
+            let summaries: Vec<String> = errors.iter().map(|e| e.summary(file)).collect();
+            if report.summary.is_empty() {
+                report.summary = format!("{} error compile", errors.len());
+            }
+            (AttemptVerdict::CompileError, summaries)
+        }
+        Verdict::Crashed => (AttemptVerdict::Crashed, Vec::new()),
+        Verdict::Failed => (AttemptVerdict::Failed, Vec::new()),
+    };
+    let mut attempt = Attempt::new(code, attempt_verdict, report.summary);
+    attempt.diagnostics = diagnostics;
+    attempt.output = report.output;
+    progress.record_attempt(&exercise.id, attempt);
+    (verdict, report.failure_line)
+}
+
+/// Cek `code` tanpa menyimpan riwayat, untuk `rustquest validate`.
+/// Mengembalikan verdict beserta ringkasan alasannya.
+pub fn check(exercise: &Exercise, code: &str) -> (Verdict, String) {
+    let mut report = Report::default();
+    let verdict = check_code(exercise, code, &mut report);
+    (verdict, report.summary)
+}
+
+/// Error compile dari `code_template` apa adanya. `None` jika template
+/// tidak bisa di-compile sama sekali (workspace atau `rustc` bermasalah).
+pub fn template_errors(exercise: &Exercise) -> Option<Vec<Diagnostic>> {
+    let workspace = Workspace::new().ok()?;
+    compile_quietly(exercise, &workspace, &exercise.code_template, &mut Report::default())
+}
+
+/// Compile dan jalankan `code_template` apa adanya tanpa input, misal
+/// untuk memastikan kuis tebak output punya jawaban yang benar.
+pub fn run_template(exercise: &Exercise) -> Result<String, String> {
+    let workspace = Workspace::new().map_err(|e| e.to_string())?;
+    match compile_quietly(exercise, &workspace, &exercise.code_template, &mut Report::default()) {
+        Some(errors) if errors.is_empty() => {}
+        Some(errors) => return Err(format!("template gagal compile ({} error)", errors.len())),
+        None => return Err("rustc tidak bisa dijalankan".to_string()),
+    }
+    let mut command = Command::new(workspace.binary_path());
+    command.env("RUST_BACKTRACE", "0").current_dir(workspace.path());
+    let output = sandbox::run(command, "", &Limits::default()).map_err(|e| e.to_string())?;
+    match output.termination {
+        Termination::Exited(0) => Ok(output.stdout),
+        other => Err(format!("template berhenti dengan {:?}", other)),
+    }
+}
+
+/// Compile, jalankan, dan validasi kode learner untuk satu exercise.
+fn check_code(exercise: &Exercise, code: &str, report: &mut Report) -> Verdict {
+    // Kuis dinilai langsung, tidak butuh compiler
+    if let ExerciseKind::Quiz(quiz) = &exercise.kind {
+        return check_quiz(quiz, code, report);
+    }
+    
+    if !toolchain_supports(exercise) {
+        report.summary = match toolchain::current() {
+            Some(_) => "Versi rustc terlalu lama".to_string(),
+            None => "rustc tidak ditemukan".to_string(),
+        };
+        return Verdict::Failed;
+    }
+    if let Some(reason) = exercise.unavailable() {
+        println!("{} {}", "🚫 Exercise tidak tersedia:".yellow(), reason);
+        report.summary = format!("Exercise tidak tersedia: {}", reason);
+        return Verdict::Failed;
+    }
+    
+    // Setiap percobaan punya workspace sendiri
+    let workspace = match Workspace::new() {
+        Ok(workspace) => workspace,
+        Err(e) => {
+            println!("{} {}", "❌ Gagal membuat workspace:".red(), e);
+            report.summary = format!("Gagal membuat workspace: {}", e);
+            return Verdict::Failed;
+        }
+    };
+    let binary = workspace.binary_path();
     
     // Compile
     println!("\n{}", "🔧 Compiling...".yellow());
+    let build = match &exercise.kind {
+        ExerciseKind::CompileFail(expected) => {
+            return check_compile_fail(exercise, &workspace, code, expected, report);
+        }
+        ExerciseKind::PredictError { ask_line } => {
+            return check_prediction(exercise, &workspace, code, *ask_line, report);
+        }
+        ExerciseKind::Cargo(project) => cargo_build(project, &workspace, code, &binary),
+        _ => {
+            let source = workspace.source_path();
+            if let Err(e) = fs::write(&source, code) {
+                println!("{} {}", "❌ Gagal menulis kode:".red(), e);
+                report.summary = format!("Gagal menulis kode: {}", e);
+                return Verdict::Failed;
+            }
+            compile(&workspace, &source, code, &binary, &compile_args(exercise))
+        }
+    };
+    if let Err(errors) = build {
+        return Verdict::CompileError(errors);
+    }
+    
+    // Cek struktur kode sebelum dijalankan
+    let mut violations = rules::check(code, &exercise.rules);
+    if let ExerciseKind::FixBug {} = exercise.kind {
+        violations.extend(rules::changed_signatures(&exercise.code_template, code));
+    }
+    if !violations.is_empty() {
+        println!("{}", "═══════════════════════════════════════".red());
+        println!("{}", "📐 Kode belum sesuai aturan exercise:".red().bold());
+        println!("{}", "═══════════════════════════════════════".red());
+        for violation in &violations {
+            println!("  • {}", violation.yellow());
+        }
+        report.summary = "Kode belum sesuai aturan exercise".to_string();
+        return Verdict::Failed;
+    }
+    
+    // Run
+    println!("{}", "🚀 Running...".yellow());
+    let limits = Limits::default();
+    match run_cases(exercise, &workspace, code, &binary, &limits, report) {
+        Verdict::Passed => {}
+        verdict => return verdict,
+    }
+    
+    if let Some(tests) = &exercise.hidden_tests {
+        if !run_hidden_tests(exercise, &workspace, code, tests, &limits) {
+            report.summary = "Test tersembunyi gagal".to_string();
+            return Verdict::Failed;
+        }
+    }
+    
+    if let Some(budget) = &exercise.performance {
+        if !check_performance(exercise, budget, &workspace, &binary, &limits, report) {
+            return Verdict::Failed;
+        }
+    }
+    
+    report.summary = "Semua pengecekan lulus".to_string();
+    print_success();
+    Verdict::Passed
+}
+
+fn print_success() {
+    println!("{}", "═══════════════════════════════════════".green());
+    println!("{}", "🎉 BENAR! Quest selesai!".green().bold());
+    println!("{}", "═══════════════════════════════════════".green());
+}
+
+/// Compile `code` sebagai file learner tanpa menampilkan error compile.
+/// `None` jika file tidak bisa ditulis atau `rustc` tidak bisa dijalankan.
+fn compile_quietly(
+    exercise: &Exercise,
+    workspace: &Workspace,
+    code: &str,
+    report: &mut Report,
+) -> Option<Vec<Diagnostic>> {
+    let source = workspace.source_path();
+    if let Err(e) = fs::write(&source, code) {
+        println!("{} {}", "❌ Gagal menulis kode:".red(), e);
+        report.summary = format!("Gagal menulis kode: {}", e);
+        return None;
+    }
+    let args = ["--edition", exercise.edition.as_str()];
+    match rustc(workspace, &source, code, &workspace.binary_path(), &args) {
+        Compiled::Built => Some(Vec::new()),
+        Compiled::Failed(errors) => Some(errors),
+        Compiled::Unavailable => {
+            report.summary = "rustc tidak bisa dijalankan".to_string();
+            None
+        }
+    }
+}
+
+/// Apakah salah satu error cocok dengan error code (dan baris, jika
+/// diminta) yang diharapkan.
+fn has_error(errors: &[Diagnostic], file: &str, code: &str, line: Option<usize>) -> bool {
+    errors.iter().any(|error| {
+        error.code() == Some(code) && line.is_none_or(|line| error.primary_line(file) == Some(line))
+    })
+}
+
+fn check_quiz(quiz: &Quiz, answer: &str, report: &mut Report) -> Verdict {
+    if quiz.is_correct(answer) {
+        report.summary = "Jawaban benar".to_string();
+        print_success();
+        return Verdict::Passed;
+    }
+    let feedback = quiz.feedback(answer);
+    println!("{}", "═══════════════════════════════════════".red());
+    println!("{}", "❌ SALAH! Jawaban belum tepat.".red().bold());
+    println!("{}", "═══════════════════════════════════════".red());
+    println!("{}", feedback);
+    println!("\n{}", "💡 Coba lagi!".yellow());
+    report.summary = feedback;
+    Verdict::Failed
+}
+
+/// Exercise `CompileFail`: lulus jika compiler menolak kode learner dengan
+/// error yang diminta.
+fn check_compile_fail(
+    exercise: &Exercise,
+    workspace: &Workspace,
+    code: &str,
+    expected: &ExpectedError,
+    report: &mut Report,
+) -> Verdict {
+    let Some(errors) = compile_quietly(exercise, workspace, code, report) else {
+        return Verdict::Failed;
+    };
+    if errors.is_empty() {
+        println!("{}", "═══════════════════════════════════════".red());
+        println!("{}", "❌ SALAH! Kode kamu berhasil compile.".red().bold());
+        println!("{}", "═══════════════════════════════════════".red());
+        println!("Compiler seharusnya menolak kode ini dengan error {}.", expected.code.bold());
+        report.summary = format!("Kode berhasil compile, seharusnya error {}", expected.code);
+        return Verdict::Failed;
+    }
+    
+    let file = exercise.source_file();
+    println!("{}", "🧪 Compiler menolak kode kamu:".cyan().bold());
+    println!();
+    for error in &errors {
+        diagnostics::render(error, code, file);
+    }
+    
+    if has_error(&errors, file, &expected.code, expected.line) {
+        report.summary = format!("Compiler menolak kode dengan {}", expected.code);
+        print_success();
+        return Verdict::Passed;
+    }
+    
+    println!("{}", "═══════════════════════════════════════".red());
+    println!("{}", "❌ SALAH! Error dari compiler belum sesuai.".red().bold());
+    println!("{}", "═══════════════════════════════════════".red());
+    match expected.line {
+        Some(line) if has_error(&errors, file, &expected.code, None) => {
+            println!(
+                "Error {} sudah muncul, tapi seharusnya ditunjuk di baris {}.",
+                expected.code.bold(),
+                line
+            );
+            report.summary = format!("Error {} bukan di baris {}", expected.code, line);
+        }
+        _ => {
+            let found: Vec<&str> = errors.iter().map(|e| e.code().unwrap_or("tanpa kode")).collect();
+            println!(
+                "Error yang muncul: {}; yang diminta: {}.",
+                found.join(", "),
+                expected.code.bold()
+            );
+            report.summary = format!("Error {}, seharusnya {}", found.join(", "), expected.code);
+        }
+    }
+    println!("\n{}", "💡 Coba lagi!".yellow());
+    Verdict::Failed
+}
+
+/// Exercise `PredictError`: compile template lalu cocokkan tebakan learner
+/// (misal `E0499 baris 4`) dengan diagnostic yang sebenarnya.
+fn check_prediction(
+    exercise: &Exercise,
+    workspace: &Workspace,
+    answer: &str,
+    ask_line: bool,
+    report: &mut Report,
+) -> Verdict {
+    let mut parts = answer.split_whitespace();
+    let code = parts.next().unwrap_or_default();
+    let line = parts.last().and_then(|line| line.parse().ok());
+    
+    let template = &exercise.code_template;
+    let Some(errors) = compile_quietly(exercise, workspace, template, report) else {
+        return Verdict::Failed;
+    };
+    if errors.is_empty() {
+        println!("{}", "⚠️  Template exercise ini ternyata berhasil compile. Laporkan ke pembuat quest.".yellow());
+        report.summary = "Template berhasil compile".to_string();
+        return Verdict::Failed;
+    }
+    
+    let file = exercise.source_file();
+    // Tidak ada baris 0, jadi tebakan tanpa baris tidak pernah cocok
+    let expected_line = if ask_line { line.or(Some(0)) } else { None };
+    if has_error(&errors, file, code, expected_line) {
+        println!("\n{}", "🧪 Error dari compiler:".cyan().bold());
+        println!();
+        for error in &errors {
+            diagnostics::render(error, template, file);
+        }
+        report.summary = format!("Tebakan benar: {}", answer);
+        print_success();
+        return Verdict::Passed;
+    }
+    
+    println!("{}", "═══════════════════════════════════════".red());
+    println!("{}", "❌ SALAH! Tebakan belum tepat.".red().bold());
+    println!("{}", "═══════════════════════════════════════".red());
+    if ask_line && has_error(&errors, file, code, None) {
+        println!("Error code {} benar, tapi barisnya belum tepat.", code.bold());
+        report.summary = format!("Tebakan {}: baris salah", answer);
+    } else {
+        println!("Compiler tidak menghasilkan error {}.", code.bold());
+        report.summary = format!("Tebakan {} salah", answer);
+    }
+    println!("\n{}", "💡 Coba lagi! Baca kodenya baris demi baris.".yellow());
+    Verdict::Failed
+}
+
+/// Argumen `rustc` untuk exercise satu file; exercise performa di-compile
+/// dengan optimasi.
+fn compile_args(exercise: &Exercise) -> Vec<&str> {
+    let mut args = vec!["--edition", exercise.edition.as_str()];
+    if exercise.performance.is_some() {
+        args.extend(performance::OPTIMIZE_ARGS);
+    }
+    args
+}
+
+/// Ukur binary learner dan solusi referensi, lalu bandingkan dengan budget.
+fn check_performance(
+    exercise: &Exercise,
+    budget: &PerformanceBudget,
+    workspace: &Workspace,
+    binary: &Path,
+    limits: &Limits,
+    report: &mut Report,
+) -> bool {
+    let Some(case) = exercise.cases.first() else {
+        return true;
+    };
+    println!("\n{}", format!("⏱️  Mengukur performa ({} kali)...", budget.runs).yellow());
+    let mine = match performance::measure(binary, workspace.path(), case, budget.runs, limits) {
+        Ok(measurement) => measurement,
+        Err(e) => {
+            println!("{} {}", "❌ Gagal mengukur performa:".red(), e);
+            report.summary = format!("Gagal mengukur performa: {}", e);
+            return false;
+        }
+    };
+    let reference = measure_solution(exercise, budget, case, limits);
+    
+    if performance::print_report(budget, &mine, reference.as_ref()) {
+        return true;
+    }
+    report.summary = performance::summary(budget, &mine);
+    println!("{}", "═══════════════════════════════════════".red());
+    println!("{}", "🐢 Output benar, tapi program melebihi budget performa.".red().bold());
+    println!("{}", "═══════════════════════════════════════".red());
+    println!("{}", "💡 Coba kurangi alokasi dan kerja yang diulang-ulang.".yellow());
+    false
+}
+
+/// Compile dan ukur solusi referensi di workspace terpisah. `None` jika
+/// exercise tidak punya solusi atau solusinya gagal diukur.
+fn measure_solution(
+    exercise: &Exercise,
+    budget: &PerformanceBudget,
+    case: &TestCase,
+    limits: &Limits,
+) -> Option<Measurement> {
+    let solution = exercise.solution.as_ref()?;
+    let workspace = Workspace::new().ok()?;
+    let source = workspace.source_path();
+    let binary = workspace.binary_path();
+    fs::write(&source, &solution.code).ok()?;
+    println!("{}", "📏 Mengukur solusi referensi...".white());
+    compile(&workspace, &source, &solution.code, &binary, &compile_args(exercise)).ok()?;
+    performance::measure(&binary, workspace.path(), case, budget.runs, limits).ok()
+}
+
+/// Cek apakah `rustc` yang terpasang cukup baru untuk exercise ini. Jika
+/// tidak, tampilkan cara meng-update-nya alih-alih compile error yang
+/// membingungkan.
+/// Cek apakah `rustc` terpasang dan cukup baru untuk exercise ini. Hanya
+/// untuk exercise yang `needs_compiler`; kuis tidak butuh compiler.
+fn toolchain_supports(exercise: &Exercise) -> bool {
+    let Some(toolchain) = toolchain::current() else {
+        toolchain::print_missing();
+        return false;
+    };
+    let Some(required) = exercise.required_rustc() else {
+        return true;
+    };
+    if toolchain.version >= required {
+        return true;
+    }
+    println!("{}", "═══════════════════════════════════════".yellow());
+    println!("{}", "⚠️  Versi Rust kamu terlalu lama untuk exercise ini".yellow().bold());
+    println!("{}", "═══════════════════════════════════════".yellow());
+    println!("Dibutuhkan : rustc {} atau lebih baru", required);
+    println!("Terpasang  : rustc {}", toolchain.version);
+    println!("\n{} {}", "💡 Update dengan:".yellow(), "rustup update stable".cyan());
+    false
+}
+
+/// Hasil satu test case.
+enum CaseOutcome {
+    Passed,
+    /// Dihentikan sandbox karena waktu, memori, atau output melebihi batas.
+    Limit(String),
+    /// Panic yang tidak diharapkan, atau dihentikan oleh sinyal.
+    Crashed(String),
+    /// Exit code atau pesan panic tidak sesuai.
+    WrongExit(String),
+    WrongOutput,
+    WrongStderr,
+}
+
+impl CaseOutcome {
+    fn label(&self) -> String {
+        match self {
+            CaseOutcome::Passed => "✅ Lulus".green().to_string(),
+            CaseOutcome::Limit(message) => message.red().to_string(),
+            CaseOutcome::Crashed(_) => "💥 Crash".red().to_string(),
+            CaseOutcome::WrongExit(_) => "❌ Exit status salah".red().to_string(),
+            CaseOutcome::WrongOutput => "❌ Output salah".red().to_string(),
+            CaseOutcome::WrongStderr => "❌ Stderr salah".red().to_string(),
+        }
+    }
+    
+    fn summary(&self) -> String {
+        match self {
+            CaseOutcome::Passed => "Lulus".to_string(),
+            CaseOutcome::Limit(message)
+            | CaseOutcome::Crashed(message)
+            | CaseOutcome::WrongExit(message) => message.clone(),
+            CaseOutcome::WrongOutput => "Output tidak sesuai".to_string(),
+            CaseOutcome::WrongStderr => "Stderr tidak sesuai".to_string(),
+        }
+    }
+}
+
+/// Bandingkan hasil menjalankan program dengan semua harapan test case:
+/// cara program berhenti, stdout, lalu stderr.
+fn judge(case: &TestCase, output: &Execution, limits: &Limits) -> CaseOutcome {
+    let code = match output.termination {
+        Termination::Exited(code) => code,
+        Termination::Signaled(_) => {
+            return CaseOutcome::Crashed(termination_message(output.termination, limits).unwrap_or_default());
+        }
+        _ => return CaseOutcome::Limit(termination_message(output.termination, limits).unwrap_or_default()),
+    };
+    
+    // Panic di Rust keluar dengan exit code 101
+    let panic = panic_message(&output.stderr).filter(|_| code == 101);
+    match (&case.exit, panic) {
+        (ExpectedExit::Code(_), Some(message)) => {
+            return CaseOutcome::Crashed(format!("panic: {}", message));
+        }
+        (ExpectedExit::Code(expected), None) if code != *expected => {
+            return CaseOutcome::WrongExit(format!(
+                "Program keluar dengan exit code {}, seharusnya {}",
+                code, expected
+            ));
+        }
+        (ExpectedExit::Panic(expected), None) => {
+            return CaseOutcome::WrongExit(format!(
+                "Program seharusnya panic dengan pesan {}, tapi keluar dengan exit code {}",
+                expected.describe(),
+                code
+            ));
+        }
+        (ExpectedExit::Panic(expected), Some(message)) if !expected.matches(&message) => {
+            return CaseOutcome::WrongExit(format!(
+                "Pesan panic \"{}\" tidak sesuai, seharusnya {}",
+                message,
+                expected.describe()
+            ));
+        }
+        _ => {}
+    }
+    
+    if !case.expected.matches(&output.stdout) {
+        CaseOutcome::WrongOutput
+    } else if case.stderr.as_ref().is_some_and(|expected| !expected.matches(&output.stderr)) {
+        CaseOutcome::WrongStderr
+    } else {
+        CaseOutcome::Passed
+    }
+}
+
+/// Tebak baris kode yang membuat case gagal: lokasi panic dari stderr,
+/// atau `println!` ke-n jika baris output ke-n yang pertama berbeda.
+/// Tebakan output hanya akurat untuk kode tanpa loop, tapi cukup untuk
+/// menunjuk blank yang paling mungkin salah.
+fn failure_line(
+    exercise: &Exercise,
+    code: &str,
+    case: &TestCase,
+    output: &Execution,
+    outcome: &CaseOutcome,
+) -> Option<usize> {
+    match outcome {
+        CaseOutcome::Crashed(_) | CaseOutcome::WrongExit(_) => {
+            panic_line(&output.stderr, exercise.source_file())
+        }
+        CaseOutcome::WrongOutput => {
+            let expected = case.expected.expected_text()?;
+            let index = first_different_line(&expected, &output.stdout);
+            let prints: Vec<usize> = code
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.contains("println!"))
+                .map(|(i, _)| i + 1)
+                .collect();
+            prints.get(index).or(prints.last()).copied()
+        }
+        _ => None,
+    }
+}
+
+/// Nomor baris di `file` tempat program panic, dari
+/// `panicked at src/main.rs:12:5` (format baru) atau
+/// `panicked at 'pesan', src/main.rs:12:5` (format lama).
+fn panic_line(stderr: &str, file: &str) -> Option<usize> {
+    let location = Regex::new(&format!(r"(?:^|[\s/']){}:(\d+):\d+", regex::escape(file))).ok()?;
+    let line = stderr.lines().find(|line| line.contains(" panicked at "))?;
+    location.captures(line)?[1].parse().ok()
+}
+
+/// Index baris output pertama yang berbeda dari expected (whitespace
+/// berlebih diabaikan). Jika satu output adalah awalan output lainnya,
+/// index baris pertama yang hilang atau berlebih.
+fn first_different_line(expected: &str, actual: &str) -> usize {
+    let normalize = |line: &str| line.split_whitespace().collect::<Vec<_>>().join(" ");
+    let expected: Vec<String> = expected.lines().map(normalize).collect();
+    let actual: Vec<String> = actual.lines().map(normalize).collect();
+    expected
+        .iter()
+        .zip(&actual)
+        .position(|(e, a)| e != a)
+        .unwrap_or(expected.len().min(actual.len()))
+}
+
+/// Ambil pesan panic dari stderr program Rust. Mendukung format lama
+/// (`panicked at 'pesan', src/main.rs:1:1`) dan format baru (lokasi dulu,
+/// pesan di baris berikutnya).
+fn panic_message(stderr: &str) -> Option<String> {
+    let mut lines = stderr.lines();
+    while let Some(line) = lines.next() {
+        // Versi baru menyertakan id thread: `thread 'main' (123) panicked at`
+        let Some((_, rest)) = line
+            .strip_prefix("thread '")
+            .and_then(|line| line.split_once(" panicked at "))
+        else {
+            continue;
+        };
+        if let Some(old) = rest.strip_prefix('\'') {
+            return old.rsplit_once("', ").map(|(message, _)| message.to_string());
+        }
+        let message: Vec<&str> = lines
+            .take_while(|l| !l.starts_with("note: ") && !l.starts_with("stack backtrace:"))
+            .collect();
+        return Some(message.join("\n"));
+    }
+    None
+}
+
+/// Jalankan binary learner sekali untuk setiap test case exercise.
+/// Lulus hanya jika semua case lulus.
+fn run_cases(
+    exercise: &Exercise,
+    workspace: &Workspace,
+    code: &str,
+    binary: &Path,
+    limits: &Limits,
+    report: &mut Report,
+) -> Verdict {
+    // Exercise yang hanya dinilai dengan test tersembunyi tidak punya case
+    if exercise.cases.is_empty() {
+        return Verdict::Passed;
+    }
+    
+    let mut runs = Vec::new();
+    for case in &exercise.cases {
+        let mut command = Command::new(binary);
+        command
+            .args(&case.args)
+            .env("RUST_BACKTRACE", "0")
+            .current_dir(workspace.path());
+        match sandbox::run(command, &case.stdin, limits) {
+            Ok(output) => {
+                let outcome = judge(case, &output, limits);
+                runs.push((case, output, outcome));
+            }
+            Err(e) => {
+                println!("{} {}", "❌ Gagal menjalankan:".red(), e);
+                report.summary = format!("Gagal menjalankan: {}", e);
+                return Verdict::Failed;
+            }
+        }
+    }
+    
+    // Exercise dengan satu case: tampilkan output apa adanya
+    if let [(case, output, outcome)] = runs.as_slice() {
+        report.output = output.stdout.clone();
+        report.summary = outcome.summary();
+        report.failure_line = failure_line(exercise, code, case, output, outcome);
+        if let CaseOutcome::Limit(_) = outcome {
+            report_termination(output, limits);
+            return Verdict::Failed;
+        }
+        
+        println!("\n{}", "📤 Output kamu:".cyan().bold());
+        println!("{}", output.stdout.white());
+        
+        if let CaseOutcome::Passed = outcome {
+            return Verdict::Passed;
+        }
+        let headline = match outcome {
+            CaseOutcome::Crashed(_) => "💥 PROGRAM CRASH!",
+            CaseOutcome::WrongExit(_) => "❌ SALAH! Status keluar program tidak sesuai.",
+            CaseOutcome::WrongStderr => "❌ SALAH! Stderr tidak sesuai.",
+            _ => "❌ SALAH! Output tidak sesuai.",
+        };
+        println!("{}", "═══════════════════════════════════════".red());
+        println!("{}", headline.red().bold());
+        println!("{}", "═══════════════════════════════════════".red());
+        explain_failure(case, output, outcome);
+        println!("\n{}", "💡 Coba lagi!".yellow());
+        return case_verdict(outcome);
+    }
+    
+    println!("\n{}", "📋 Hasil per case:".cyan().bold());
+    println!("  {:<4}{:<28}Hasil", "No", "Case");
+    for (i, (case, _, outcome)) in runs.iter().enumerate() {
+        println!("  {:<4}{:<28}{}", i + 1, case.name, outcome.label());
+    }
+    
+    let Some((case, output, outcome)) = runs
+        .iter()
+        .find(|(_, _, outcome)| !matches!(outcome, CaseOutcome::Passed))
+    else {
+        if let Some((_, output, _)) = runs.first() {
+            report.output = output.stdout.clone();
+        }
+        return Verdict::Passed;
+    };
+    
+    report.output = output.stdout.clone();
+    report.summary = format!("Case \"{}\": {}", case.name, outcome.summary());
+    report.failure_line = failure_line(exercise, code, case, output, outcome);
+    println!("\n{} {}", "🔍 Case gagal:".yellow().bold(), case.name);
+    if !case.args.is_empty() {
+        println!("{} {}", "Argumen:".yellow(), case.args.join(" "));
+    }
+    println!("{}", "Input (stdin):".yellow());
+    println!("{}", case.stdin.trim_end().white());
+    // Program yang dihentikan sandbox sudah dijelaskan di tabel; diff hanya
+    // berguna jika program selesai normal
+    if let CaseOutcome::Limit(_) = outcome {
+        println!("{}", "Output kamu:".yellow());
+        println!("{}", output.stdout.trim_end().white());
+    } else {
+        explain_failure(case, output, outcome);
+    }
+    println!("\n{}", "💡 Coba lagi! Program harus benar untuk semua input.".yellow());
+    case_verdict(outcome)
+}
+
+/// Crash dilaporkan sebagai verdict tersendiri, bukan output salah.
+fn case_verdict(outcome: &CaseOutcome) -> Verdict {
+    match outcome {
+        CaseOutcome::Passed => Verdict::Passed,
+        CaseOutcome::Crashed(_) => Verdict::Crashed,
+        _ => Verdict::Failed,
+    }
+}
+
+fn explain_failure(case: &TestCase, output: &Execution, outcome: &CaseOutcome) {
+    match outcome {
+        CaseOutcome::Crashed(message) => {
+            println!("{} {}", "💥".red(), message.red());
+            println!("{}", "💡 Program berhenti sebelum selesai. Cek unwrap(), index array, dan panic!.".yellow());
+        }
+        CaseOutcome::WrongExit(message) => println!("{}", message.yellow()),
+        CaseOutcome::WrongOutput => report_mismatch(case, &output.stdout),
+        CaseOutcome::WrongStderr => {
+            println!("{}", "Stderr kamu:".yellow());
+            println!("{}", output.stderr.trim_end().white());
+            if let Some(expected) = &case.stderr {
+                println!("{} {}", "Expected stderr:".yellow(), expected.describe());
+            }
+        }
+        CaseOutcome::Passed | CaseOutcome::Limit(_) => {}
+    }
+}
+
+/// Tunjukkan kenapa output tidak cocok: diff per baris jika matcher punya
+/// teks expected, atau deskripsi pola yang diharapkan.
+fn report_mismatch(case: &TestCase, actual: &str) {
+    match case.expected.expected_text() {
+        Some(expected) => {
+            diff::print_output_diff(&expected, actual);
+            if let OutputMatcher::Approx { tolerance, .. } = &case.expected {
+                println!("  {}", format!("(angka boleh berbeda maksimal ±{})", tolerance).white());
+            }
+        }
+        None => {
+            println!("{}", "Output kamu:".yellow());
+            println!("{}", actual.trim_end().white());
+            println!("{} {}", "Expected:".yellow(), case.expected.describe());
+        }
+    }
+}
+
+/// Compile `source` (berisi `code`) dengan `rustc`. Error compile
+/// ditampilkan ke learner dalam format yang ramah pemula dan dikembalikan
+/// untuk dianalisis lebih lanjut.
+fn compile(
+    workspace: &Workspace,
+    source: &Path,
+    code: &str,
+    binary: &Path,
+    extra_args: &[&str],
+) -> Result<(), Vec<Diagnostic>> {
+    match rustc(workspace, source, code, binary, extra_args) {
+        Compiled::Built => Ok(()),
+        Compiled::Failed(errors) => {
+            report_compile_errors(&errors, code, &file_name(source));
+            Err(errors)
+        }
+        Compiled::Unavailable => Err(Vec::new()),
+    }
+}
+
+/// Hasil menjalankan `rustc`.
+enum Compiled {
+    Built,
+    Failed(Vec<Diagnostic>),
+    /// `rustc` tidak bisa dijalankan; pesannya sudah ditampilkan.
+    Unavailable,
+}
+
+/// Jalankan `rustc` (atau ambil hasilnya dari cache) tanpa menampilkan
+/// error compile, untuk exercise yang memang mengharapkan error.
+fn rustc(workspace: &Workspace, source: &Path, code: &str, binary: &Path, extra_args: &[&str]) -> Compiled {
+    // Pakai nama file relatif agar span diagnostic tidak berisi path temp
+    let file = file_name(source);
+    
+    let key = cache::key(&["rustc", &file, code, &extra_args.join(" ")]);
+    match cache::lookup(&key) {
+        Some(cache::Entry::Built(cached)) if fs::copy(&cached[0], binary).is_ok() => {
+            println!("{}", "⚡ Memakai hasil compile dari cache.".white());
+            return Compiled::Built;
+        }
+        Some(cache::Entry::Failed(errors)) => {
+            println!("{}", "⚡ Memakai hasil compile dari cache.".white());
+            return Compiled::Failed(errors);
+        }
+        _ => {}
+    }
+    
     let compile = Command::new("rustc")
-        .args([temp_file, "-o", temp_bin])
+        .arg(&file)
+        .arg("-o")
+        .arg(binary)
+        .arg("--error-format=json")
+        .args(extra_args)
+        .current_dir(workspace.path())
         .output();
     
     match compile {
+        Ok(output) if output.status.success() => {
+            cache::store_built(&key, &[binary.to_path_buf()]);
+            Compiled::Built
+        }
         Ok(output) => {
-            if !output.status.success() {
-                println!("{}", "❌ COMPILE ERROR!".red().bold());
-                println!("{}", String::from_utf8_lossy(&output.stderr).red());
-                println!("\n{}", "💡 Coba lagi! Periksa syntax kamu.".yellow());
-                return false;
+            let errors = diagnostics::parse(&String::from_utf8_lossy(&output.stderr));
+            if !errors.is_empty() {
+                cache::store_failed(&key, &errors);
             }
+            Compiled::Failed(errors)
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            toolchain::print_missing();
+            Compiled::Unavailable
         }
         Err(e) => {
             println!("{} {}", "❌ Gagal compile:".red(), e);
-            return false;
+            Compiled::Unavailable
         }
     }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|f| f.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+/// Build exercise Cargo di workspace, lalu salin binary hasilnya ke
+/// `binary`.
+fn cargo_build(
+    project: &CargoProject,
+    workspace: &Workspace,
+    code: &str,
+    binary: &Path,
+) -> Result<(), Vec<Diagnostic>> {
+    let executables = cargo_build_executables(project, workspace, code, false)?;
+    let Some(built) = executables.first() else {
+        println!("{}", "❌ Cargo tidak menghasilkan binary.".red());
+        return Err(Vec::new());
+    };
+    if let Err(e) = fs::copy(built, binary) {
+        println!("{} {}", "❌ Gagal menyalin binary:".red(), e);
+        return Err(Vec::new());
+    }
+    Ok(())
+}
+
+/// Siapkan dan build project Cargo, kembalikan executable yang dihasilkan.
+fn cargo_build_executables(
+    project: &CargoProject,
+    workspace: &Workspace,
+    main_code: &str,
+    tests: bool,
+) -> Result<Vec<PathBuf>, Vec<Diagnostic>> {
+    let mut parts = vec!["cargo", if tests { "test" } else { "build" }, main_code];
+    for (path, content) in &project.files {
+        parts.push(path);
+        parts.push(content);
+    }
+    let key = cache::key(&parts);
+    match cache::lookup(&key) {
+        Some(cache::Entry::Built(cached)) => {
+            println!("{}", "⚡ Memakai hasil build dari cache.".white());
+            return Ok(cached);
+        }
+        Some(cache::Entry::Failed(errors)) => {
+            println!("{}", "⚡ Memakai hasil build dari cache.".white());
+            report_compile_errors(&errors, main_code, cargo_project::MAIN_FILE);
+            return Err(errors);
+        }
+        None => {}
+    }
     
-    // Run
-    println!("{}", "🚀 Running...".yellow());
-    let run = Command::new(temp_bin).output();
+    if let Err(e) = cargo_project::prepare(project, workspace.path(), main_code) {
+        println!("{} {}", "❌ Gagal menyiapkan project:".red(), e);
+        return Err(Vec::new());
+    }
     
-    match run {
-        Ok(output) => {
-            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-            let expected = exercise.expected_output.trim();
-            
-            println!("\n{}", "📤 Output kamu:".cyan().bold());
-            println!("{}", stdout.white());
-            
-            if stdout.contains(expected) {
-                println!("{}", "═══════════════════════════════════════".green());
-                println!("{}", "🎉 BENAR! Quest selesai!".green().bold());
-                println!("{}", "═══════════════════════════════════════".green());
-                progress.mark_completed(&exercise.id);
-                return true;
+    match cargo_project::build(workspace.path(), tests) {
+        Ok(build) if build.success => {
+            cache::store_built(&key, &build.executables);
+            Ok(build.executables)
+        }
+        Ok(build) => {
+            // Build gagal tanpa diagnostic (misal dependency tidak ada)
+            // bergantung pada environment, jadi tidak di-cache
+            if !build.diagnostics.is_empty() {
+                cache::store_failed(&key, &build.diagnostics);
+            }
+            if build.diagnostics.is_empty() {
+                println!("{}", "❌ BUILD GAGAL!".red().bold());
+                println!("{}", build.stderr.red());
+                println!(
+                    "\n{}",
+                    "💡 Jika dependency tidak ditemukan, pastikan folder vendor RustQuest tersedia (lihat README).".yellow()
+                );
             } else {
-                println!("{}", "═══════════════════════════════════════".red());
-                println!("{}", "❌ SALAH! Output tidak sesuai.".red().bold());
-                println!("{}", "═══════════════════════════════════════".red());
-                println!("{} {}", "Expected:".yellow(), expected);
-                println!("\n{}", "💡 Coba lagi!".yellow());
-                return false;
+                report_compile_errors(&build.diagnostics, main_code, cargo_project::MAIN_FILE);
             }
+            Err(build.diagnostics)
         }
         Err(e) => {
-            println!("{} {}", "❌ Gagal menjalankan:".red(), e);
-            return false;
+            println!("{} {}", "❌ Gagal menjalankan cargo:".red(), e);
+            Err(Vec::new())
         }
     }
 }
+
+fn report_compile_errors(errors: &[Diagnostic], code: &str, file: &str) {
+    println!("{}", "❌ COMPILE ERROR!".red().bold());
+    println!();
+    // Kode yang digabung dengan test tersembunyi tidak boleh membocorkan
+    // isi test lewat potongan kode di diagnostic
+    for error in &hidden_tests::redact(errors, code) {
+        diagnostics::render(error, code, file);
+    }
+    println!("{}", "💡 Coba lagi! Periksa syntax kamu.".yellow());
+}
+
+/// Compile kode learner bersama test tersembunyi dengan `rustc --test`,
+/// lalu tampilkan hasil setiap test. Lulus hanya jika semua test lulus.
+fn run_hidden_tests(
+    exercise: &Exercise,
+    workspace: &Workspace,
+    code: &str,
+    tests: &str,
+    limits: &Limits,
+) -> bool {
+    println!("{}", "🧪 Menjalankan test tersembunyi...".yellow());
+    
+    let test_code = hidden_tests::build_source(code, tests);
+    let harnesses = match &exercise.kind {
+        ExerciseKind::Cargo(project) => {
+            match cargo_build_executables(project, workspace, &test_code, true) {
+                Ok(harnesses) => harnesses,
+                Err(_) => return false,
+            }
+        }
+        _ => {
+            let source = workspace.path().join("hidden_tests.rs");
+            let binary = workspace.path().join("hidden_tests");
+            if let Err(e) = fs::write(&source, &test_code) {
+                println!("{} {}", "❌ Gagal menulis test:".red(), e);
+                return false;
+            }
+            let args = ["--test", "--edition", &exercise.edition];
+            if compile(workspace, &source, &test_code, &binary, &args).is_err() {
+                return false;
+            }
+            vec![binary]
+        }
+    };
+    
+    let mut results = Vec::new();
+    let mut output = None;
+    let mut incomplete = false;
+    for harness in &harnesses {
+        let mut command = Command::new(harness);
+        command
+            .arg("--test-threads=1")
+            .env("RUST_BACKTRACE", "0")
+            .current_dir(workspace.path());
+        let run = match sandbox::run(command, "", limits) {
+            Ok(run) => run,
+            Err(e) => {
+                println!("{} {}", "❌ Gagal menjalankan test:".red(), e);
+                return false;
+            }
+        };
+        results.extend(hidden_tests::parse_results(&run.stdout));
+        // Harness yang keluar di tengah jalan (misal kode learner memanggil
+        // process::exit) tidak sempat mencetak ringkasan hasil
+        incomplete |= !hidden_tests::completed(&run.stdout);
+        // Simpan harness pertama yang gagal untuk dilaporkan
+        let failed = run.termination != Termination::Exited(0) || incomplete;
+        if output.is_none() || failed {
+            output = Some(run);
+            if failed {
+                break;
+            }
+        }
+    }
+    let Some(output) = output else {
+        println!("{}", "❌ Tidak ada test yang dijalankan.".red());
+        return false;
+    };
+    
+    for result in &results {
+        if result.passed {
+            println!("  {} {}", "✅".green(), result.name);
+        } else {
+            println!("  {} {}", "❌".red(), result.name.red());
+            for line in result.message.lines() {
+                println!("      {}", line.white());
+            }
+        }
+    }
+    
+    if !matches!(output.termination, Termination::Exited(_)) {
+        return report_termination(&output, limits);
+    }
+    
+    let failed = results.iter().filter(|r| !r.passed).count();
+    if failed > 0 || results.is_empty() || incomplete || output.termination != Termination::Exited(0) {
+        println!("{}", "═══════════════════════════════════════".red());
+        if incomplete && failed == 0 {
+            println!("{}", "❌ Test berhenti sebelum semua selesai dijalankan.".red().bold());
+        } else {
+            println!(
+                "{}",
+                format!("❌ {} dari {} test gagal.", failed, results.len()).red().bold()
+            );
+        }
+        println!("{}", "═══════════════════════════════════════".red());
+        if incomplete {
+            println!("{}", "💡 Program keluar di tengah test. Jangan panggil std::process::exit di fungsi yang dites.".yellow());
+        }
+        println!("\n{}", "💡 Coba lagi! Pastikan fungsi kamu benar untuk semua input.".yellow());
+        return false;
+    }
+    
+    println!("{}", format!("✅ Semua {} test lulus!", results.len()).green());
+    true
+}
+
+/// Pesan singkat untuk program yang dihentikan oleh sandbox, atau `None`
+/// jika program selesai normal.
+fn termination_message(termination: Termination, limits: &Limits) -> Option<String> {
+    let message = match termination {
+        Termination::Exited(_) => return None,
+        Termination::TimedOut => format!(
+            "⏱️  WAKTU HABIS! Program berjalan lebih dari {} detik (infinite loop?)",
+            limits.wall_time.as_secs()
+        ),
+        Termination::MemoryLimit => format!(
+            "💾 BATAS MEMORI TERLAMPAUI! Program memakai lebih dari {} MB",
+            limits.memory_bytes / (1024 * 1024)
+        ),
+        Termination::OutputLimit => format!(
+            "📜 OUTPUT TERLALU BESAR! Program mencetak lebih dari {} KB",
+            limits.max_output_bytes / 1024
+        ),
+        Termination::Signaled(signal) => format!("💥 Program dihentikan oleh sinyal {}", signal),
+    };
+    Some(message)
+}
+
+/// Tampilkan verdict untuk program yang dihentikan oleh sandbox.
+/// Mengembalikan `true` jika program selesai normal dan output layak dicek.
+fn report_termination(output: &Execution, limits: &Limits) -> bool {
+    let Some(message) = termination_message(output.termination, limits) else {
+        return true;
+    };
+    
+    println!("{}", "═══════════════════════════════════════".red());
+    println!("{}", message.red().bold());
+    println!("{}", "═══════════════════════════════════════".red());
+    if !output.stderr.trim().is_empty() {
+        println!("{}", output.stderr.red());
+    }
+    println!("\n{}", "💡 Coba lagi!".yellow());
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sebelum Rust 1.73: pesan di baris yang sama, diapit kutip
+    const OLD_PANIC: &str = "thread 'main' panicked at 'index out of bounds: the len is 3 but the index is 5', main.rs:4:5\n\
+                             note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+    // Sejak Rust 1.73: lokasi dulu, pesan di baris berikutnya
+    const NEW_PANIC: &str = "thread 'main' panicked at main.rs:4:5:\n\
+                             index out of bounds: the len is 3 but the index is 5\n\
+                             note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+
+    #[test]
+    fn panic_message_before_rust_1_73() {
+        assert_eq!(
+            panic_message(OLD_PANIC).as_deref(),
+            Some("index out of bounds: the len is 3 but the index is 5")
+        );
+    }
+
+    #[test]
+    fn panic_message_old_format_keeps_quotes_inside_message() {
+        let stderr = "thread 'main' panicked at 'nilai 'x' salah', main.rs:2:5\n";
+        assert_eq!(panic_message(stderr).as_deref(), Some("nilai 'x' salah"));
+    }
+
+    #[test]
+    fn panic_message_since_rust_1_73() {
+        assert_eq!(
+            panic_message(NEW_PANIC).as_deref(),
+            Some("index out of bounds: the len is 3 but the index is 5")
+        );
+    }
+
+    #[test]
+    fn panic_message_new_format_multiline_and_thread_id() {
+        let stderr = "thread 'main' (4242) panicked at main.rs:7:5:\n\
+                      assertion `left == right` failed\n  left: 1\n right: 2\n\
+                      stack backtrace:\n   0: rust_begin_unwind\n";
+        assert_eq!(
+            panic_message(stderr).as_deref(),
+            Some("assertion `left == right` failed\n  left: 1\n right: 2")
+        );
+    }
+
+    #[test]
+    fn panic_message_without_panic() {
+        assert_eq!(panic_message("warning: unused variable\n"), None);
+        assert_eq!(panic_message(""), None);
+    }
+
+    #[test]
+    fn panic_line_in_both_formats() {
+        assert_eq!(panic_line(OLD_PANIC, "main.rs"), Some(4));
+        assert_eq!(panic_line(NEW_PANIC, "main.rs"), Some(4));
+        let cargo = "thread 'main' panicked at src/main.rs:12:9:\nboom\n";
+        assert_eq!(panic_line(cargo, "src/main.rs"), Some(12));
+    }
+
+    #[test]
+    fn panic_line_ignores_other_files() {
+        let stderr = "thread 'main' panicked at /rustc/abc/library/core/src/option.rs:2:5:\n\
+                      called `Option::unwrap()` on a `None` value\n";
+        assert_eq!(panic_line(stderr, "main.rs"), None);
+    }
+
+    #[test]
+    fn first_different_line_ignores_extra_whitespace() {
+        assert_eq!(first_different_line("a\nb\nc", "a\nb  \nx"), 2);
+        assert_eq!(first_different_line("a b", "a   b"), 1);
+        assert_eq!(first_different_line("a\nb", "a"), 1);
+    }
+}
@@ -0,0 +1,349 @@
+// Sandbox - Run Learner Programs with Limits
+// ==========================================
+
+use colored::Colorize;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::os::unix::process::CommandExt;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, OnceLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Batas sumber daya untuk satu kali menjalankan program learner.
+#[derive(Clone, Debug)]
+pub struct Limits {
+    pub wall_time: Duration,
+    pub cpu_seconds: u64,
+    pub memory_bytes: u64,
+    pub max_output_bytes: usize,
+    /// Proses/thread tambahan yang boleh dibuat program learner, di atas
+    /// jumlah yang sudah dimiliki user saat ini.
+    pub max_processes: u64,
+    pub allow_network: bool,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            wall_time: Duration::from_secs(5),
+            cpu_seconds: 5,
+            memory_bytes: 256 * 1024 * 1024,
+            max_output_bytes: 64 * 1024,
+            max_processes: 64,
+            allow_network: false,
+        }
+    }
+}
+
+/// Bagaimana program learner berhenti.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination {
+    Exited(i32),
+    Signaled(i32),
+    TimedOut,
+    MemoryLimit,
+    OutputLimit,
+}
+
+pub struct Execution {
+    pub stdout: String,
+    pub stderr: String,
+    pub termination: Termination,
+    /// Waktu dari spawn sampai proses selesai.
+    pub elapsed: Duration,
+    /// Resident set size tertinggi proses, dalam KB.
+    pub peak_memory_kb: u64,
+}
+
+/// Jalankan `command` di bawah `limits` dengan `stdin` sebagai input.
+/// Proses dijalankan di process group sendiri dengan rlimit
+/// CPU/memori/proses dan (jika kernel mengizinkan) di network namespace
+/// kosong. Semua proses
+/// di group tersebut di-kill ketika waktu habis atau output melebihi batas.
+pub fn run(mut command: Command, stdin: &str, limits: &Limits) -> io::Result<Execution> {
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let isolate_network = !limits.allow_network && network_isolation();
+    // RLIMIT_NPROC menghitung semua task milik user, bukan hanya turunan
+    // program ini, jadi batasnya relatif terhadap jumlah task saat ini
+    let max_tasks = user_tasks().map(|tasks| tasks + limits.max_processes);
+    let child_limits = limits.clone();
+    // SAFETY: closure hanya memanggil fungsi libc yang async-signal-safe.
+    unsafe {
+        command.pre_exec(move || apply_limits(&child_limits, max_tasks, isolate_network));
+    }
+
+    let start = Instant::now();
+    let mut child = command.spawn()?;
+    let pgid = child.id() as libc::pid_t;
+
+    // Tulis stdin di thread terpisah agar input besar tidak membuat deadlock
+    // dengan pipe stdout. Error (misal program tidak membaca stdin) diabaikan.
+    if let Some(mut pipe) = child.stdin.take() {
+        let input = stdin.as_bytes().to_vec();
+        thread::spawn(move || {
+            let _ = pipe.write_all(&input);
+        });
+    }
+
+    let captured = Arc::new(AtomicUsize::new(0));
+    let overflow = Arc::new(AtomicBool::new(false));
+    let stdout = spawn_reader(child.stdout.take(), limits, &captured, &overflow);
+    let stderr = spawn_reader(child.stderr.take(), limits, &captured, &overflow);
+
+    let (termination, usage, elapsed) = wait_with_limits(pgid, start, limits, &overflow)?;
+    // Child sudah di-reap oleh wait4, jangan di-wait lagi lewat std
+    drop(child);
+
+    // Bersihkan sisa proses anak (misal hasil fork) agar pipe tertutup.
+    kill_group(pgid);
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    let stdout = String::from_utf8_lossy(&stdout).to_string();
+    let stderr = String::from_utf8_lossy(&stderr).to_string();
+
+    let termination = match termination {
+        // Reader menutup pipe saat batas tercapai, jadi program bisa saja
+        // keluar sendiri (panic karena broken pipe) sebelum sempat di-kill.
+        _ if overflow.load(Ordering::Relaxed) => Termination::OutputLimit,
+        Termination::Signaled(libc::SIGXCPU) => Termination::TimedOut,
+        Termination::Signaled(libc::SIGABRT) if stderr.contains("memory allocation of") => {
+            Termination::MemoryLimit
+        }
+        other => other,
+    };
+
+    Ok(Execution {
+        stdout,
+        stderr,
+        termination,
+        elapsed,
+        // ru_maxrss di Linux dalam KB
+        peak_memory_kb: usage.ru_maxrss.max(0) as u64,
+    })
+}
+
+/// Tunggu proses selesai dengan `wait4` agar pemakaian sumber dayanya
+/// (termasuk memori puncak) ikut didapat. `wait4` memblokir di thread
+/// terpisah supaya waktu selesai dicatat tepat saat proses di-reap,
+/// sementara thread ini mengawasi batas waktu dan output.
+fn wait_with_limits(
+    pid: libc::pid_t,
+    start: Instant,
+    limits: &Limits,
+    overflow: &AtomicBool,
+) -> io::Result<(Termination, libc::rusage, Duration)> {
+    let (sender, reaped) = mpsc::channel();
+    thread::spawn(move || {
+        let result = wait4(pid).map(|(termination, usage)| (termination, usage, start.elapsed()));
+        let _ = sender.send(result);
+    });
+
+    let mut limit_hit = None;
+    loop {
+        match reaped.recv_timeout(POLL_INTERVAL) {
+            Ok(result) => {
+                let (termination, usage, elapsed) = result?;
+                return Ok((limit_hit.unwrap_or(termination), usage, elapsed));
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(io::Error::other("proses tidak bisa di-wait"));
+            }
+        }
+
+        if limit_hit.is_none() {
+            if overflow.load(Ordering::Relaxed) {
+                limit_hit = Some(Termination::OutputLimit);
+            } else if start.elapsed() >= limits.wall_time {
+                limit_hit = Some(Termination::TimedOut);
+            }
+            if limit_hit.is_some() {
+                kill_group(pid);
+            }
+        }
+    }
+}
+
+fn wait4(pid: libc::pid_t) -> io::Result<(Termination, libc::rusage)> {
+    let mut status = 0;
+    // SAFETY: rusage adalah struct C biasa yang boleh diisi nol.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    loop {
+        // SAFETY: pointer ke variable lokal yang valid selama pemanggilan.
+        let reaped = unsafe { libc::wait4(pid, &mut status, 0, &mut usage) };
+        if reaped >= 0 {
+            break;
+        }
+        let error = io::Error::last_os_error();
+        if error.kind() != io::ErrorKind::Interrupted {
+            return Err(error);
+        }
+    }
+    let termination = if libc::WIFEXITED(status) {
+        Termination::Exited(libc::WEXITSTATUS(status))
+    } else if libc::WIFSIGNALED(status) {
+        Termination::Signaled(libc::WTERMSIG(status))
+    } else {
+        Termination::Signaled(0)
+    };
+    Ok((termination, usage))
+}
+
+fn spawn_reader<R: Read + Send + 'static>(
+    source: Option<R>,
+    limits: &Limits,
+    captured: &Arc<AtomicUsize>,
+    overflow: &Arc<AtomicBool>,
+) -> JoinHandle<Vec<u8>> {
+    let max = limits.max_output_bytes;
+    let captured = Arc::clone(captured);
+    let overflow = Arc::clone(overflow);
+
+    thread::spawn(move || {
+        let mut data = Vec::new();
+        let Some(mut source) = source else {
+            return data;
+        };
+        let mut buf = [0u8; 8192];
+        loop {
+            let n = match source.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => n,
+            };
+            let total = captured.fetch_add(n, Ordering::Relaxed) + n;
+            if total > max {
+                let room = n.saturating_sub(total - max);
+                data.extend_from_slice(&buf[..room]);
+                overflow.store(true, Ordering::Relaxed);
+                break;
+            }
+            data.extend_from_slice(&buf[..n]);
+        }
+        data
+    })
+}
+
+fn apply_limits(limits: &Limits, max_tasks: Option<u64>, isolate_network: bool) -> io::Result<()> {
+    // SAFETY: dipanggil di child setelah fork, sebelum exec.
+    unsafe {
+        if libc::setpgid(0, 0) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        set_limit(libc::RLIMIT_CPU, limits.cpu_seconds)?;
+        set_limit(libc::RLIMIT_AS, limits.memory_bytes)?;
+        if let Some(max_tasks) = max_tasks {
+            set_limit(libc::RLIMIT_NPROC, max_tasks)?;
+        }
+        set_limit(libc::RLIMIT_FSIZE, limits.max_output_bytes as u64)?;
+        set_limit(libc::RLIMIT_CORE, 0)?;
+
+        if isolate_network {
+            unshare_network()?;
+        }
+    }
+    Ok(())
+}
+
+/// Jumlah proses dan thread milik user ini (real uid, seperti yang dihitung
+/// RLIMIT_NPROC), dari `/proc`. `None` jika `/proc` tidak tersedia.
+fn user_tasks() -> Option<u64> {
+    // SAFETY: getuid selalu berhasil.
+    let uid = unsafe { libc::getuid() }.to_string();
+    let mut tasks = 0;
+    for entry in fs::read_dir("/proc").ok()?.filter_map(Result::ok) {
+        if !entry.file_name().to_string_lossy().bytes().all(|b| b.is_ascii_digit()) {
+            continue;
+        }
+        // Proses bisa saja sudah selesai saat dibaca
+        let Ok(status) = fs::read_to_string(entry.path().join("status")) else {
+            continue;
+        };
+        let field = |name: &str| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix(name))
+                .and_then(|value| value.split_whitespace().next())
+        };
+        if field("Uid:") == Some(uid.as_str()) {
+            tasks += field("Threads:").and_then(|n| n.parse::<u64>().ok()).unwrap_or(1);
+        }
+    }
+    Some(tasks)
+}
+
+/// Apakah kernel mengizinkan network namespace baru. Dicek sekali dengan
+/// proses percobaan; jika tidak bisa, tampilkan peringatan satu kali
+/// karena program learner akan berjalan dengan akses jaringan penuh.
+fn network_isolation() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| {
+        let mut probe = Command::new("true");
+        probe.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+        // SAFETY: unshare_network hanya memanggil unshare yang async-signal-safe.
+        unsafe {
+            probe.pre_exec(unshare_network);
+        }
+        let available = probe.status().is_ok_and(|status| status.success());
+        if !available {
+            println!(
+                "{}",
+                "⚠️  Isolasi jaringan tidak didukung sistem ini: program learner berjalan dengan akses jaringan."
+                    .yellow()
+            );
+        }
+        available
+    })
+}
+
+/// Pindah ke network namespace kosong (hanya loopback). Tanpa hak root,
+/// coba lewat user namespace baru.
+#[cfg(target_os = "linux")]
+fn unshare_network() -> io::Result<()> {
+    // SAFETY: unshare hanya mengubah namespace proses pemanggil.
+    unsafe {
+        if libc::unshare(libc::CLONE_NEWNET) != 0
+            && libc::unshare(libc::CLONE_NEWUSER | libc::CLONE_NEWNET) != 0
+        {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn unshare_network() -> io::Result<()> {
+    Err(io::Error::from(io::ErrorKind::Unsupported))
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+type Resource = libc::__rlimit_resource_t;
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+type Resource = libc::c_int;
+
+unsafe fn set_limit(resource: Resource, value: u64) -> io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: value as libc::rlim_t,
+        rlim_max: value as libc::rlim_t,
+    };
+    if libc::setrlimit(resource, &limit) != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+fn kill_group(pgid: libc::pid_t) {
+    // SAFETY: mengirim sinyal ke process group milik program learner.
+    unsafe {
+        libc::killpg(pgid, libc::SIGKILL);
+    }
+}
@@ -0,0 +1,91 @@
+// Toolchain Detection - Installed rustc Version
+// =============================================
+
+use colored::Colorize;
+use std::fmt;
+use std::process::Command;
+use std::sync::OnceLock;
+
+/// Versi Rust dalam bentuk `major.minor.patch`, bisa dibandingkan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RustVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl RustVersion {
+    /// Parse versi seperti `1.65`, `1.80.1`, atau `1.82.0-nightly`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let release = text.trim().split('-').next()?;
+        let mut parts = release.split('.').map(|part| part.parse::<u32>());
+        let major = parts.next()?.ok()?;
+        let minor = parts.next()?.ok()?;
+        let patch = match parts.next() {
+            Some(patch) => patch.ok()?,
+            None => 0,
+        };
+        Some(RustVersion { major, minor, patch })
+    }
+}
+
+impl fmt::Display for RustVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Versi `rustc` pertama yang mendukung `edition`. Edition 2015 didukung
+/// semua versi.
+pub fn edition_minimum(edition: &str) -> Option<RustVersion> {
+    let (minor, patch) = match edition {
+        "2018" => (31, 0),
+        "2021" => (56, 0),
+        "2024" => (85, 0),
+        _ => return None,
+    };
+    Some(RustVersion { major: 1, minor, patch })
+}
+
+/// Compiler Rust yang terpasang, dari `rustc --version --verbose`.
+pub struct Toolchain {
+    pub version: RustVersion,
+    pub host: String,
+    /// Output lengkap `rustc --version --verbose`, termasuk commit hash.
+    pub verbose: String,
+}
+
+/// Toolchain yang terdeteksi, atau `None` jika `rustc` tidak bisa dijalankan.
+/// Deteksi hanya dilakukan sekali.
+pub fn current() -> Option<&'static Toolchain> {
+    static TOOLCHAIN: OnceLock<Option<Toolchain>> = OnceLock::new();
+    TOOLCHAIN.get_or_init(detect).as_ref()
+}
+
+fn detect() -> Option<Toolchain> {
+    let output = Command::new("rustc").args(["--version", "--verbose"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let verbose = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let field = |name: &str| {
+        verbose
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .map(|value| value.trim().to_string())
+    };
+    Some(Toolchain {
+        version: RustVersion::parse(&field("release:")?)?,
+        host: field("host:").unwrap_or_default(),
+        verbose,
+    })
+}
+
+/// Pesan untuk learner jika `rustc` tidak ditemukan.
+pub fn print_missing() {
+    println!("{}", "❌ Compiler Rust (rustc) tidak ditemukan!".red().bold());
+    println!("RustQuest butuh `rustc` di PATH untuk mengecek jawabanmu.");
+    println!("  1. Install Rust lewat {}", "https://rustup.rs".cyan());
+    println!("  2. Tutup lalu buka lagi terminal (agar PATH ter-update)");
+    println!("  3. Cek dengan: {}", "rustc --version".cyan());
+}
@@ -0,0 +1,250 @@
+// Quest Validation - Self-Check for Quest Content
+// ===============================================
+
+use crate::exercises::{Exercise, ExerciseKind, Quest};
+use crate::quiz::Quiz;
+use crate::runner::{self, Verdict};
+use crate::toolchain;
+use colored::Colorize;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::os::unix::io::AsRawFd;
+
+/// Hasil satu pengecekan di tabel.
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Ok,
+    Problem,
+    /// Tidak berlaku untuk jenis exercise ini.
+    Skipped,
+}
+
+impl Status {
+    fn icon(self) -> &'static str {
+        match self {
+            Status::Ok => "✅",
+            Status::Problem => "❌",
+            Status::Skipped => "➖",
+        }
+    }
+}
+
+struct Row {
+    solution: Status,
+    template: Status,
+    notes: Vec<String>,
+    /// Exercise tidak bisa dicek di mesin ini, misal exercise Cargo tanpa
+    /// crate vendor.
+    unavailable: bool,
+}
+
+/// Perintah `rustquest validate`: compile dan jalankan solusi referensi
+/// setiap exercise, pastikan template yang belum diubah tidak lulus, dan
+/// cari id exercise yang dobel. Mengembalikan exit code: 1 jika ada
+/// masalah, atau jika ada exercise yang tidak bisa dicek kecuali
+/// `allow_skip` (`--allow-skip`).
+pub fn run(quests: &[Quest], allow_skip: bool) -> i32 {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for exercise in quests.iter().flat_map(|quest| &quest.exercises) {
+        *counts.entry(&exercise.id).or_insert(0) += 1;
+    }
+
+    println!("\n{}", "🔍 Validasi quest".cyan().bold());
+    println!("  {:<20}{:<8}{:<10}Catatan", "Exercise", "Solusi", "Template");
+
+    let mut total = 0;
+    let mut problems = 0;
+    let mut skipped = 0;
+    for exercise in quests.iter().flat_map(|quest| &quest.exercises) {
+        let mut row = quietly(|| check(exercise));
+        if counts[exercise.id.as_str()] > 1 {
+            row.notes.push(format!("id dipakai {} exercise", counts[exercise.id.as_str()]));
+        }
+
+        let failed = row.solution == Status::Problem
+            || row.template == Status::Problem
+            || counts[exercise.id.as_str()] > 1;
+        total += 1;
+        if failed {
+            problems += 1;
+        } else if row.unavailable {
+            skipped += 1;
+        }
+        let notes = row.notes.join("; ");
+        println!(
+            "  {:<20}{:<7}{:<9}{}",
+            exercise.id,
+            row.solution.icon(),
+            row.template.icon(),
+            if failed { notes.red().to_string() } else { notes }
+        );
+    }
+
+    println!();
+    if problems > 0 {
+        println!(
+            "{}",
+            format!("❌ {} dari {} exercise bermasalah.", problems, total).red().bold()
+        );
+        if skipped > 0 {
+            println!("{}", format!("➖ {} exercise tidak bisa dicek.", skipped).yellow());
+        }
+        1
+    } else if skipped > 0 && !allow_skip {
+        println!(
+            "{}",
+            format!("⚠️  {} dari {} exercise tidak bisa dicek (lihat catatan).", skipped, total)
+                .yellow()
+                .bold()
+        );
+        println!("{}", "Siapkan yang dibutuhkan, atau jalankan dengan --allow-skip untuk menerimanya.".yellow());
+        1
+    } else if skipped > 0 {
+        println!(
+            "{}",
+            format!("✅ {} exercise valid, {} dilewati (--allow-skip).", total - skipped, skipped)
+                .green()
+                .bold()
+        );
+        0
+    } else {
+        println!("{}", format!("✅ Semua {} exercise valid.", total).green().bold());
+        0
+    }
+}
+
+fn check(exercise: &Exercise) -> Row {
+    if let Some(reason) = exercise.unavailable() {
+        return Row {
+            solution: Status::Skipped,
+            template: Status::Skipped,
+            notes: vec![format!("tidak tersedia: {}", reason)],
+            unavailable: true,
+        };
+    }
+    // Kuis tebak output juga di-compile untuk mengecek jawabannya
+    let compiles = exercise.needs_compiler()
+        || matches!(exercise.kind, ExerciseKind::Quiz(Quiz::PredictOutput { .. }));
+    if compiles && toolchain::current().is_none() {
+        return Row {
+            solution: Status::Skipped,
+            template: Status::Skipped,
+            notes: vec!["tidak tersedia: rustc tidak ditemukan".to_string()],
+            unavailable: true,
+        };
+    }
+    match &exercise.kind {
+        ExerciseKind::Quiz(Quiz::PredictOutput { expected }) => {
+            // Jawaban kuis tebak output adalah output template itu sendiri
+            let (solution, notes) = match runner::run_template(exercise) {
+                Ok(output) if expected.matches(&output) => (Status::Ok, Vec::new()),
+                Ok(output) => (
+                    Status::Problem,
+                    vec![format!("output template tidak cocok: {}", first_line(&output))],
+                ),
+                Err(e) => (Status::Problem, vec![e]),
+            };
+            Row { solution, template: Status::Skipped, notes, unavailable: false }
+        }
+        ExerciseKind::Quiz(_) => Row {
+            solution: Status::Skipped,
+            template: Status::Skipped,
+            notes: vec!["kuis, dicek saat load".to_string()],
+            unavailable: false,
+        },
+        ExerciseKind::PredictError { .. } => {
+            let (template, notes) = match runner::template_errors(exercise) {
+                Some(errors) if errors.iter().any(|e| e.code().is_some()) => (Status::Ok, Vec::new()),
+                Some(errors) if errors.is_empty() => {
+                    (Status::Problem, vec!["template berhasil compile".to_string()])
+                }
+                Some(_) => (Status::Problem, vec!["error template tidak punya error code".to_string()]),
+                None => (Status::Problem, vec!["template tidak bisa di-compile".to_string()]),
+            };
+            Row { solution: Status::Skipped, template, notes, unavailable: false }
+        }
+        _ => {
+            let mut notes = Vec::new();
+            let solution = match &exercise.solution {
+                None => {
+                    notes.push("tidak ada solusi referensi".to_string());
+                    Status::Problem
+                }
+                Some(solution) => match runner::check(exercise, &solution.code) {
+                    (Verdict::Passed, _) => Status::Ok,
+                    (verdict, summary) => {
+                        notes.push(format!("solusi: {}", describe(&verdict, &summary)));
+                        Status::Problem
+                    }
+                },
+            };
+            let template = match runner::check(exercise, &exercise.code_template) {
+                (Verdict::Passed, _) => {
+                    notes.push("template lulus tanpa diubah".to_string());
+                    Status::Problem
+                }
+                _ => Status::Ok,
+            };
+            Row { solution, template, notes, unavailable: false }
+        }
+    }
+}
+
+fn describe(verdict: &Verdict, summary: &str) -> String {
+    match verdict {
+        Verdict::CompileError(errors) if summary.is_empty() => {
+            format!("{} error compile", errors.len())
+        }
+        _ if summary.is_empty() => "gagal".to_string(),
+        _ => summary.to_string(),
+    }
+}
+
+fn first_line(text: &str) -> String {
+    text.lines().next().unwrap_or_default().to_string()
+}
+
+/// Jalankan `f` dengan stdout dibuang. Runner menampilkan hasil compile,
+/// output, dan diff untuk learner; di sini cukup tabel ringkasannya.
+fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let _silenced = Silenced::start();
+    f()
+}
+
+/// Stdout yang sedang dialihkan ke /dev/null. Stdout asli dikembalikan
+/// saat guard di-drop, termasuk ketika pengecekan panic.
+struct Silenced {
+    saved: libc::c_int,
+}
+
+impl Silenced {
+    /// `None` jika stdout tidak bisa dialihkan; output tetap tampil.
+    fn start() -> Option<Self> {
+        let _ = io::stdout().flush();
+        let null = OpenOptions::new().write(true).open("/dev/null").ok()?;
+        // SAFETY: dup/dup2 hanya pada file descriptor stdout dan /dev/null
+        // yang valid selama fungsi ini.
+        let saved = unsafe { libc::dup(libc::STDOUT_FILENO) };
+        if saved < 0 {
+            return None;
+        }
+        if unsafe { libc::dup2(null.as_raw_fd(), libc::STDOUT_FILENO) } < 0 {
+            // SAFETY: `saved` hasil dup di atas dan belum dipakai.
+            unsafe { libc::close(saved) };
+            return None;
+        }
+        Some(Silenced { saved })
+    }
+}
+
+impl Drop for Silenced {
+    fn drop(&mut self) {
+        let _ = io::stdout().flush();
+        // SAFETY: mengembalikan stdout asli yang disimpan di `start`.
+        unsafe {
+            libc::dup2(self.saved, libc::STDOUT_FILENO);
+            libc::close(self.saved);
+        }
+    }
+}
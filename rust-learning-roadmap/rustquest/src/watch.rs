@@ -0,0 +1,91 @@
+// Watch Mode - Recheck the Current Exercise on Every Save
+// =======================================================
+
+use crate::drafts;
+use crate::exercises::{Exercise, Quest};
+use crate::progress::Progress;
+use crate::runner::{self, Verdict};
+use crate::toolchain;
+use colored::Colorize;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Mode `rustquest watch`: tulis exercise pertama yang belum selesai ke
+/// folder draft, lalu compile dan validasi ulang setiap kali file disimpan.
+/// Jika lulus, lanjut otomatis ke exercise berikutnya.
+pub fn run(progress: &mut Progress, quests: &[Quest]) {
+    // Semua exercise di mode watch di-compile
+    if toolchain::current().is_none() {
+        toolchain::print_missing();
+        return;
+    }
+    loop {
+        let next = quests
+            .iter()
+            .flat_map(|quest| quest.exercises.iter().map(move |exercise| (quest, exercise)))
+            // Exercise pertanyaan tidak punya file untuk di-watch
+            .find(|(_, exercise)| {
+                !exercise.is_question()
+                    && exercise.unavailable().is_none()
+                    && !progress.is_completed(&exercise.id)
+            });
+
+        let Some((quest, exercise)) = next else {
+            println!("\n{}", "🎖️  Semua exercise sudah selesai! Kerja bagus, Rustacean! 🦀".green().bold());
+            return;
+        };
+
+        let path = match drafts::ensure_draft(exercise) {
+            Ok(path) => path,
+            Err(e) => {
+                println!("{} {}", "❌ Gagal menyiapkan draft:".red(), e);
+                return;
+            }
+        };
+
+        println!("\n{}", "═══════════════════════════════════════".cyan());
+        println!("👀 {} - Exercise {}", quest.name.cyan().bold(), exercise.id);
+        println!("{}", "═══════════════════════════════════════".cyan());
+        println!("\n{}", exercise.question.cyan());
+        println!("{} {}", "📝 Edit file:".yellow().bold(), path.display());
+        println!("{}", "Simpan file untuk mengecek jawaban. Tekan Ctrl+C untuk keluar.".white());
+
+        watch_until_passed(exercise, &path, progress);
+        progress.mark_completed(&exercise.id);
+    }
+}
+
+/// Cek draft sekali, lalu cek ulang setiap kali file berubah sampai lulus.
+fn watch_until_passed(exercise: &Exercise, path: &Path, progress: &mut Progress) {
+    let mut last_modified = None;
+    loop {
+        let modified = modified_time(path);
+        if modified.is_some() && modified != last_modified {
+            last_modified = modified;
+            if check_draft(exercise, path, progress) {
+                return;
+            }
+            println!("\n{}", "👀 Menunggu perubahan file...".white());
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn check_draft(exercise: &Exercise, path: &Path, progress: &mut Progress) -> bool {
+    let code = match fs::read_to_string(path) {
+        Ok(code) => code,
+        Err(e) => {
+            println!("{} {}", "❌ Gagal membaca draft:".red(), e);
+            return false;
+        }
+    };
+    matches!(runner::submit(exercise, &code, progress), Verdict::Passed)
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
@@ -0,0 +1,58 @@
+// Workspace - Isolated Directory per Attempt
+// ==========================================
+
+use colored::Colorize;
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Set env ini (misal `RUSTQUEST_KEEP_WORKSPACE=1`) agar workspace tidak
+/// dihapus setelah percobaan selesai, berguna untuk debugging.
+const KEEP_ENV: &str = "RUSTQUEST_KEEP_WORKSPACE";
+
+/// Nama file source learner di dalam workspace.
+pub const SOURCE_FILE: &str = "main.rs";
+
+/// Direktori temporary unik untuk satu percobaan. Source, binary, dan file
+/// lain hasil compile hanya ditulis di sini, lalu dihapus otomatis saat
+/// `Workspace` di-drop.
+pub struct Workspace {
+    dir: Option<TempDir>,
+    keep: bool,
+}
+
+impl Workspace {
+    pub fn new() -> io::Result<Self> {
+        let dir = tempfile::Builder::new().prefix("rustquest-").tempdir()?;
+        let keep = env::var_os(KEEP_ENV).is_some_and(|v| !v.is_empty() && v != "0");
+        Ok(Workspace {
+            dir: Some(dir),
+            keep,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        self.dir.as_ref().expect("workspace sudah ditutup").path()
+    }
+
+    pub fn source_path(&self) -> PathBuf {
+        self.path().join(SOURCE_FILE)
+    }
+
+    pub fn binary_path(&self) -> PathBuf {
+        self.path().join("main")
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        if !self.keep {
+            return;
+        }
+        if let Some(dir) = self.dir.take() {
+            let path = dir.keep();
+            println!("{} {}", "📁 Workspace disimpan di:".white(), path.display());
+        }
+    }
+}